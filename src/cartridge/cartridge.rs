@@ -0,0 +1,128 @@
+use super::cartridge_error::CartridgeError;
+use super::cartridge_header::{compute_global_checksum, compute_header_checksum, CartridgeHeader};
+
+use std::fs;
+use std::path::Path;
+
+pub struct Cartridge {
+    _header: CartridgeHeader,
+    _rom: Vec<u8>,
+    _warnings: Vec<CartridgeError>,
+}
+
+impl Cartridge {
+    pub fn from_file(path: &Path) -> Result<Cartridge, CartridgeError> {
+        let data = fs::read(path)?;
+        return Cartridge::from_bytes(data);
+    }
+    
+    pub fn from_bytes(mut data: Vec<u8>) -> Result<Cartridge, CartridgeError> {
+        let header = CartridgeHeader::parse(&data)?;
+        
+        let header_checksum = compute_header_checksum(&data);
+        if header_checksum != header.header_checksum {
+            return Err(CartridgeError::HeaderChecksumMismatch {expected: header.header_checksum, actual: header_checksum});
+        }
+        
+        let rom_size = header.rom_size()?;
+        if data.len() < rom_size {
+            return Err(CartridgeError::Truncated {length: data.len(), expected_length: rom_size});
+        }
+        
+        // Overdumped and padded images run fine on hardware, which never sees past the declared size
+        let mut warnings = Vec::new();
+        if data.len() > rom_size {
+            warnings.push(CartridgeError::RomSizeMismatch {length: data.len(), expected_length: rom_size});
+            data.truncate(rom_size);
+        }
+        
+        // The boot ROM never checks the global checksum, so plenty of working images get it wrong
+        let global_checksum = compute_global_checksum(&data);
+        if global_checksum != header.global_checksum {
+            warnings.push(CartridgeError::GlobalChecksumMismatch {expected: header.global_checksum, actual: global_checksum});
+        }
+        
+        return Ok(Cartridge {_header: header, _rom: data, _warnings: warnings});
+    }
+    
+    pub fn header(&self) -> &CartridgeHeader {
+        return &self._header;
+    }
+    
+    // Problems with the image that real hardware would not notice
+    pub fn warnings(&self) -> &[CartridgeError] {
+        return &self._warnings;
+    }
+    
+    pub fn into_rom(self) -> Vec<u8> {
+        return self._rom;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::cartridge_header::{GLOBAL_CHECKSUM_ADDRESS, HEADER_CHECKSUM_ADDRESS};
+    use super::super::testing::build_test_rom;
+    
+    #[test]
+    fn test_from_bytes_valid_image_loads() {
+        let rom = build_test_rom(0x01, 0x02, 0x00);
+        
+        let cartridge = Cartridge::from_bytes(rom).unwrap();
+        
+        assert_eq!(cartridge.header().title, "TEST");
+        assert_eq!(cartridge.warnings().is_empty(), true);
+        assert_eq!(cartridge.into_rom().len(), 0x20000);
+    }
+    
+    #[test]
+    fn test_from_bytes_bad_header_checksum_returns_error() {
+        let mut rom = build_test_rom(0x00, 0x00, 0x00);
+        rom[HEADER_CHECKSUM_ADDRESS] = rom[HEADER_CHECKSUM_ADDRESS].wrapping_add(1);
+        
+        let result = Cartridge::from_bytes(rom);
+        
+        assert_eq!(matches!(result, Err(CartridgeError::HeaderChecksumMismatch {..})), true);
+    }
+    
+    #[test]
+    fn test_from_bytes_bad_global_checksum_loads_with_warning() {
+        let mut rom = build_test_rom(0x00, 0x00, 0x00);
+        rom[GLOBAL_CHECKSUM_ADDRESS] = rom[GLOBAL_CHECKSUM_ADDRESS].wrapping_add(1);
+        
+        let cartridge = Cartridge::from_bytes(rom).unwrap();
+        
+        assert_eq!(cartridge.warnings().len(), 1);
+        assert_eq!(matches!(cartridge.warnings()[0], CartridgeError::GlobalChecksumMismatch {..}), true);
+    }
+    
+    #[test]
+    fn test_from_bytes_missing_banks_returns_truncated() {
+        let mut rom = build_test_rom(0x01, 0x01, 0x00);
+        rom.truncate(0x8000);
+        
+        let result = Cartridge::from_bytes(rom);
+        
+        assert_eq!(matches!(result, Err(CartridgeError::Truncated {length: 0x8000, expected_length: 0x10000})), true);
+    }
+    
+    #[test]
+    fn test_from_bytes_extra_data_loads_declared_size_with_warning() {
+        let mut rom = build_test_rom(0x00, 0x00, 0x00);
+        rom.extend_from_slice(&[0; 0x10]);
+        
+        let cartridge = Cartridge::from_bytes(rom).unwrap();
+        
+        assert_eq!(cartridge.warnings().len(), 1);
+        assert_eq!(matches!(cartridge.warnings()[0], CartridgeError::RomSizeMismatch {length: 0x8010, expected_length: 0x8000}), true);
+        assert_eq!(cartridge.into_rom().len(), 0x8000);
+    }
+    
+    #[test]
+    fn test_from_file_missing_file_returns_io_error() {
+        let result = Cartridge::from_file(Path::new("does/not/exist.gb"));
+        
+        assert_eq!(matches!(result, Err(CartridgeError::Io(_))), true);
+    }
+}
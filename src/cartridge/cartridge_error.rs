@@ -0,0 +1,62 @@
+use crate::as_hex;
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+#[derive(Debug)]
+pub enum CartridgeError {
+    Io(io::Error),
+    // The image ends before the header or before the size the header declares
+    Truncated {length: usize, expected_length: usize},
+    // Only reported through Cartridge::warnings, as is GlobalChecksumMismatch
+    RomSizeMismatch {length: usize, expected_length: usize},
+    UnknownRomSize {code: u8},
+    UnknownRamSize {code: u8},
+    UnsupportedCartridgeType {code: u8},
+    HeaderChecksumMismatch {expected: u8, actual: u8},
+    GlobalChecksumMismatch {expected: u16, actual: u16},
+}
+
+impl fmt::Display for CartridgeError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            CartridgeError::Io(error) =>
+                write!(formatter, "Unable to read cartridge: {}", error),
+            CartridgeError::Truncated {length, expected_length} =>
+                write!(formatter, "Cartridge is truncated: {} bytes long but needs {}", as_hex!(length), as_hex!(expected_length)),
+            CartridgeError::RomSizeMismatch {length, expected_length} =>
+                write!(formatter, "Cartridge is {} bytes long but its header declares {}", as_hex!(length), as_hex!(expected_length)),
+            CartridgeError::UnknownRomSize {code} =>
+                write!(formatter, "Unknown ROM size code {}", as_hex!(code)),
+            CartridgeError::UnknownRamSize {code} =>
+                write!(formatter, "Unknown RAM size code {}", as_hex!(code)),
+            CartridgeError::UnsupportedCartridgeType {code} =>
+                write!(formatter, "Cartridge type {} is not supported", as_hex!(code)),
+            CartridgeError::HeaderChecksumMismatch {expected, actual} =>
+                write!(formatter, "Header checksum is {} but the header bytes add up to {}", as_hex!(expected), as_hex!(actual)),
+            CartridgeError::GlobalChecksumMismatch {expected, actual} =>
+                write!(formatter, "Global checksum is {} but the ROM bytes add up to {}", as_hex!(expected), as_hex!(actual)),
+        };
+    }
+}
+
+impl Error for CartridgeError {}
+
+impl From<io::Error> for CartridgeError {
+    fn from(error: io::Error) -> CartridgeError {
+        return CartridgeError::Io(error);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_display_header_checksum_mismatch_reports_both_values() {
+        let error = CartridgeError::HeaderChecksumMismatch {expected: 0x12, actual: 0x34};
+        
+        assert_eq!(error.to_string(), "Header checksum is 0x12 but the header bytes add up to 0x34");
+    }
+}
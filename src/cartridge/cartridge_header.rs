@@ -0,0 +1,202 @@
+use super::cartridge_error::CartridgeError;
+
+pub const HEADER_END: usize = 0x014F;
+
+const TITLE_START: usize = 0x0134;
+const TITLE_END: usize = 0x0143;
+const CGB_FLAG_ADDRESS: usize = 0x0143;
+const NEW_LICENSEE_START: usize = 0x0144;
+const SGB_FLAG_ADDRESS: usize = 0x0146;
+const CARTRIDGE_TYPE_ADDRESS: usize = 0x0147;
+const ROM_SIZE_ADDRESS: usize = 0x0148;
+const RAM_SIZE_ADDRESS: usize = 0x0149;
+const OLD_LICENSEE_ADDRESS: usize = 0x014B;
+const VERSION_ADDRESS: usize = 0x014C;
+pub const HEADER_CHECKSUM_ADDRESS: usize = 0x014D;
+pub const GLOBAL_CHECKSUM_ADDRESS: usize = 0x014E;
+
+const CGB_ENHANCED_FLAG: u8 = 0x80;
+const CGB_ONLY_FLAG: u8 = 0xC0;
+const SGB_SUPPORTED_FLAG: u8 = 0x03;
+// Points at the two character code in the new licensee field
+const USE_NEW_LICENSEE_CODE: u8 = 0x33;
+
+// Every cartridge type with a battery keeping its RAM or clock alive
+const BATTERY_CARTRIDGE_TYPES: [u8; 11] = [0x03, 0x06, 0x09, 0x0D, 0x0F, 0x10, 0x13, 0x1B, 0x1E, 0x22, 0xFF];
+
+const ROM_BANK_SIZE: usize = 0x4000;
+const MAX_ROM_SIZE_CODE: u8 = 0x08;
+const RAM_BANK_SIZE: usize = 0x2000;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CgbSupport {
+    None,
+    Enhanced,
+    Only,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Licensee {
+    Old(u8),
+    New(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CartridgeHeader {
+    pub title: String,
+    pub cgb_support: CgbSupport,
+    pub sgb_support: bool,
+    pub cartridge_type: u8,
+    pub rom_size_code: u8,
+    pub ram_size_code: u8,
+    pub licensee: Licensee,
+    pub version: u8,
+    pub header_checksum: u8,
+    pub global_checksum: u16,
+}
+
+impl CartridgeHeader {
+    pub fn parse(data: &[u8]) -> Result<CartridgeHeader, CartridgeError> {
+        if data.len() <= HEADER_END {
+            return Err(CartridgeError::Truncated {length: data.len(), expected_length: HEADER_END + 1});
+        }
+        
+        let cgb_support = match data[CGB_FLAG_ADDRESS] {
+            CGB_ENHANCED_FLAG => CgbSupport::Enhanced,
+            CGB_ONLY_FLAG => CgbSupport::Only,
+            _ => CgbSupport::None,
+        };
+        // Colour cartridges gave the last title byte to the CGB flag
+        let title_end = if cgb_support == CgbSupport::None { TITLE_END } else { TITLE_END - 1 };
+        let licensee = match data[OLD_LICENSEE_ADDRESS] {
+            USE_NEW_LICENSEE_CODE => Licensee::New(read_text(&data[NEW_LICENSEE_START..NEW_LICENSEE_START + 2])),
+            code => Licensee::Old(code),
+        };
+        let header = CartridgeHeader {
+            title: read_text(&data[TITLE_START..=title_end]),
+            cgb_support: cgb_support,
+            sgb_support: data[SGB_FLAG_ADDRESS] == SGB_SUPPORTED_FLAG,
+            cartridge_type: data[CARTRIDGE_TYPE_ADDRESS],
+            rom_size_code: data[ROM_SIZE_ADDRESS],
+            ram_size_code: data[RAM_SIZE_ADDRESS],
+            licensee: licensee,
+            version: data[VERSION_ADDRESS],
+            header_checksum: data[HEADER_CHECKSUM_ADDRESS],
+            global_checksum: u16::from_be_bytes([data[GLOBAL_CHECKSUM_ADDRESS], data[GLOBAL_CHECKSUM_ADDRESS + 1]]),
+        };
+        
+        header.rom_size()?;
+        header.ram_size()?;
+        return Ok(header);
+    }
+    
+    pub fn has_battery(&self) -> bool {
+        return BATTERY_CARTRIDGE_TYPES.contains(&self.cartridge_type);
+    }
+    
+    pub fn rom_size(&self) -> Result<usize, CartridgeError> {
+        if self.rom_size_code > MAX_ROM_SIZE_CODE {
+            return Err(CartridgeError::UnknownRomSize {code: self.rom_size_code});
+        }
+        return Ok((2 * ROM_BANK_SIZE) << self.rom_size_code);
+    }
+    
+    pub fn ram_size(&self) -> Result<usize, CartridgeError> {
+        return match self.ram_size_code {
+            0x00 => Ok(0),
+            // Only ever listed in homebrew, but some emulators accept it
+            0x01 => Ok(RAM_BANK_SIZE / 4),
+            0x02 => Ok(RAM_BANK_SIZE),
+            0x03 => Ok(4 * RAM_BANK_SIZE),
+            0x04 => Ok(16 * RAM_BANK_SIZE),
+            0x05 => Ok(8 * RAM_BANK_SIZE),
+            code => Err(CartridgeError::UnknownRamSize {code: code}),
+        };
+    }
+}
+
+// The value the boot ROM checks before it lets a cartridge start
+pub fn compute_header_checksum(data: &[u8]) -> u8 {
+    return data[TITLE_START..HEADER_CHECKSUM_ADDRESS].iter().fold(0, |checksum: u8, byte| checksum.wrapping_sub(*byte).wrapping_sub(1));
+}
+
+pub fn compute_global_checksum(data: &[u8]) -> u16 {
+    return data.iter().enumerate()
+        .filter(|(address, _)| *address != GLOBAL_CHECKSUM_ADDRESS && *address != GLOBAL_CHECKSUM_ADDRESS + 1)
+        .fold(0, |checksum: u16, (_, byte)| checksum.wrapping_add(*byte as u16));
+}
+
+fn read_text(bytes: &[u8]) -> String {
+    let length = bytes.iter().position(|byte| *byte == 0).unwrap_or(bytes.len());
+    return String::from_utf8_lossy(&bytes[..length]).into_owned();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::testing::build_test_rom;
+    
+    #[test]
+    fn test_parse_reads_fields() {
+        let mut rom = build_test_rom(0x03, 0x01, 0x02);
+        rom[VERSION_ADDRESS] = 0x02;
+        rom[SGB_FLAG_ADDRESS] = SGB_SUPPORTED_FLAG;
+        
+        let header = CartridgeHeader::parse(&rom).unwrap();
+        
+        assert_eq!(header.title, "TEST");
+        assert_eq!(header.cgb_support, CgbSupport::None);
+        assert_eq!(header.sgb_support, true);
+        assert_eq!(header.cartridge_type, 0x03);
+        assert_eq!(header.rom_size().unwrap(), 0x10000);
+        assert_eq!(header.ram_size().unwrap(), 0x2000);
+        assert_eq!(header.version, 0x02);
+    }
+    
+    #[test]
+    fn test_has_battery_reads_cartridge_type() {
+        let battery_header = CartridgeHeader::parse(&build_test_rom(0x13, 0x00, 0x03)).unwrap();
+        let plain_header = CartridgeHeader::parse(&build_test_rom(0x12, 0x00, 0x03)).unwrap();
+        
+        assert_eq!(battery_header.has_battery(), true);
+        assert_eq!(plain_header.has_battery(), false);
+    }
+    
+    #[test]
+    fn test_parse_cgb_title_stops_before_flag() {
+        let mut rom = build_test_rom(0x00, 0x00, 0x00);
+        rom[TITLE_START..=TITLE_END].copy_from_slice(b"ABCDEFGHIJKLMNO\xC0");
+        
+        let header = CartridgeHeader::parse(&rom).unwrap();
+        
+        assert_eq!(header.title, "ABCDEFGHIJKLMNO");
+        assert_eq!(header.cgb_support, CgbSupport::Only);
+    }
+    
+    #[test]
+    fn test_parse_new_licensee_code() {
+        let mut rom = build_test_rom(0x00, 0x00, 0x00);
+        rom[OLD_LICENSEE_ADDRESS] = USE_NEW_LICENSEE_CODE;
+        rom[NEW_LICENSEE_START..NEW_LICENSEE_START + 2].copy_from_slice(b"01");
+        
+        let header = CartridgeHeader::parse(&rom).unwrap();
+        
+        assert_eq!(header.licensee, Licensee::New(String::from("01")));
+    }
+    
+    #[test]
+    fn test_parse_unknown_ram_size_returns_error() {
+        let rom = build_test_rom(0x00, 0x00, 0x07);
+        
+        let result = CartridgeHeader::parse(&rom);
+        
+        assert_eq!(matches!(result, Err(CartridgeError::UnknownRamSize {code: 0x07})), true);
+    }
+    
+    #[test]
+    fn test_parse_short_image_returns_truncated() {
+        let result = CartridgeHeader::parse(&[0; 0x0120]);
+        
+        assert_eq!(matches!(result, Err(CartridgeError::Truncated {length: 0x0120, ..})), true);
+    }
+}
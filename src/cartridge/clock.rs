@@ -0,0 +1,20 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Where a cartridge's real-time clock gets the current time from
+pub trait Clock {
+    fn now_seconds(&self) -> u64;
+}
+
+pub struct SystemClock {}
+
+impl SystemClock {
+    pub fn new() -> SystemClock {
+        return SystemClock {};
+    }
+}
+
+impl Clock for SystemClock {
+    fn now_seconds(&self) -> u64 {
+        return SystemTime::now().duration_since(UNIX_EPOCH).map(|duration| duration.as_secs()).unwrap_or(0);
+    }
+}
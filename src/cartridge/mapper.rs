@@ -0,0 +1,130 @@
+use super::cartridge::Cartridge;
+use super::cartridge_error::CartridgeError;
+use super::clock::SystemClock;
+use super::mbc1::Mbc1;
+use super::mbc2::Mbc2;
+use super::mbc3::Mbc3;
+use super::mbc5::Mbc5;
+use super::rom_only::RomOnly;
+use crate::boxed;
+
+pub const ROM_BANK_SIZE: usize = 0x4000;
+pub const RAM_BANK_SIZE: usize = 0x2000;
+
+// What reads from a cartridge's RAM return while nothing drives the bus
+pub const DISABLED_RAM_VALUE: u8 = 0xFF;
+
+// The cartridge hardware that decides which ROM and RAM banks the CPU sees
+pub trait Mapper {
+    // Addressed like the CPU sees it, 0x0000-0x7FFF
+    fn read_rom(&self, address: u16) -> u8;
+    // Writes to the ROM area program the controller's registers instead
+    fn write_rom(&mut self, address: u16, value: u8);
+    // Addressed by the offset into the 0xA000-0xBFFF window
+    fn read_ram(&self, offset: u16) -> u8;
+    fn write_ram(&mut self, offset: u16, value: u8);
+    
+    fn ram(&self) -> &[u8];
+    fn ram_mut(&mut self) -> &mut [u8];
+    
+    // What the battery keeps alive while the console is off
+    fn save_data(&self) -> Vec<u8> {
+        return self.ram().to_vec();
+    }
+    
+    fn load_save_data(&mut self, data: &[u8]) {
+        let ram = self.ram_mut();
+        let length = ram.len().min(data.len());
+        ram[..length].copy_from_slice(&data[..length]);
+    }
+    
+    // Only rumble cartridges have a motor to turn on
+    fn is_rumble_motor_on(&self) -> bool {
+        return false;
+    }
+}
+
+pub fn build_mapper(cartridge: Cartridge) -> Result<Box<dyn Mapper>, CartridgeError> {
+    let cartridge_type = cartridge.header().cartridge_type;
+    let ram_size = cartridge.header().ram_size()?;
+    let rom = cartridge.into_rom();
+    return match cartridge_type {
+        0x00 | 0x08 | 0x09 => Ok(boxed!(RomOnly::new(rom, ram_size))),
+        0x01 ..= 0x03 => Ok(boxed!(Mbc1::new(rom, ram_size))),
+        // MBC2 carries its own RAM, so the header says it has none
+        0x05 | 0x06 => Ok(boxed!(Mbc2::new(rom))),
+        0x0F | 0x10 => Ok(boxed!(Mbc3::new_with_clock(rom, ram_size, boxed!(SystemClock::new())))),
+        0x11 ..= 0x13 => Ok(boxed!(Mbc3::new(rom, ram_size))),
+        0x19 ..= 0x1B => Ok(boxed!(Mbc5::new(rom, ram_size))),
+        0x1C ..= 0x1E => Ok(boxed!(Mbc5::new_with_rumble(rom, ram_size))),
+        code => Err(CartridgeError::UnsupportedCartridgeType {code: code}),
+    };
+}
+
+// Bank numbers wrap around the banks the ROM actually has, like the unconnected address lines do
+pub fn read_rom_bank(rom: &[u8], bank: usize, address: u16) -> u8 {
+    let bank_count = rom.len() / ROM_BANK_SIZE;
+    let bank = bank % bank_count;
+    return rom[bank * ROM_BANK_SIZE + (address as usize % ROM_BANK_SIZE)];
+}
+
+pub fn ram_index(ram: &[u8], bank: usize, offset: u16) -> Option<usize> {
+    if ram.is_empty() {
+        return None;
+    }
+    return Some((bank * RAM_BANK_SIZE + offset as usize) % ram.len());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::testing::build_test_rom;
+    
+    #[test]
+    fn test_build_mapper_rom_only_reads_rom() {
+        let cartridge = Cartridge::from_bytes(build_test_rom(0x00, 0x00, 0x00)).unwrap();
+        
+        let mapper = build_mapper(cartridge).unwrap();
+        
+        assert_eq!(mapper.read_rom(0x4000), 0x01);
+    }
+    
+    #[test]
+    fn test_build_mapper_picks_controller_from_cartridge_type() {
+        let cartridge = Cartridge::from_bytes(build_test_rom(0x06, 0x01, 0x00)).unwrap();
+        let mut mapper = build_mapper(cartridge).unwrap();
+        
+        mapper.write_rom(0x0000, 0x0A);
+        mapper.write_ram(0x0000, 0x03);
+        
+        assert_eq!(mapper.read_ram(0x0000), 0xF3);
+    }
+    
+    #[test]
+    fn test_build_mapper_unknown_type_returns_error() {
+        let cartridge = Cartridge::from_bytes(build_test_rom(0xFC, 0x00, 0x00)).unwrap();
+        
+        let result = build_mapper(cartridge);
+        
+        assert_eq!(matches!(result, Err(CartridgeError::UnsupportedCartridgeType {code: 0xFC})), true);
+    }
+    
+    #[test]
+    fn test_load_save_data_restores_ram() {
+        let cartridge = Cartridge::from_bytes(build_test_rom(0x03, 0x01, 0x02)).unwrap();
+        let mut mapper = build_mapper(cartridge).unwrap();
+        
+        mapper.load_save_data(&[0x12, 0x34]);
+        mapper.write_rom(0x0000, 0x0A);
+        
+        assert_eq!(mapper.read_ram(0x0001), 0x34);
+        assert_eq!(mapper.save_data().len(), RAM_BANK_SIZE);
+    }
+    
+    #[test]
+    fn test_read_rom_bank_wraps_bank_number() {
+        let rom = build_test_rom(0x01, 0x01, 0x00);
+        
+        assert_eq!(read_rom_bank(&rom, 5, 0x4000), 0x01);
+    }
+}
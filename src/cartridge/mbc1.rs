@@ -0,0 +1,203 @@
+use super::mapper::{ram_index, read_rom_bank, Mapper, DISABLED_RAM_VALUE};
+
+const RAM_ENABLE_END: u16 = 0x1FFF;
+const ROM_BANK_SELECT_END: u16 = 0x3FFF;
+const UPPER_BANK_SELECT_END: u16 = 0x5FFF;
+const FIXED_BANK_END: u16 = 0x3FFF;
+
+const RAM_ENABLE_VALUE: u8 = 0x0A;
+const RAM_ENABLE_MASK: u8 = 0x0F;
+const ROM_BANK_MASK: u8 = 0x1F;
+const UPPER_BANK_MASK: u8 = 0x03;
+const UPPER_BANK_SHIFT: usize = 5;
+const MODE_MASK: u8 = 0x01;
+
+pub struct Mbc1 {
+    _rom: Vec<u8>,
+    _ram: Vec<u8>,
+    _ram_enabled: bool,
+    _rom_bank: u8,
+    _upper_bank: u8,
+    // In advanced mode the upper bits also bank 0x0000-0x3FFF and the RAM
+    _advanced_mode: bool,
+}
+
+impl Mbc1 {
+    pub fn new(rom: Vec<u8>, ram_size: usize) -> Mbc1 {
+        return Mbc1 {
+            _rom: rom,
+            _ram: vec![0; ram_size],
+            _ram_enabled: false,
+            _rom_bank: 1,
+            _upper_bank: 0,
+            _advanced_mode: false,
+        };
+    }
+    
+    fn fixed_rom_bank(&self) -> usize {
+        return if self._advanced_mode { (self._upper_bank as usize) << UPPER_BANK_SHIFT } else { 0 };
+    }
+    
+    fn switchable_rom_bank(&self) -> usize {
+        return (self._upper_bank as usize) << UPPER_BANK_SHIFT | self._rom_bank as usize;
+    }
+    
+    fn ram_bank(&self) -> usize {
+        return if self._advanced_mode { self._upper_bank as usize } else { 0 };
+    }
+}
+
+impl Mapper for Mbc1 {
+    fn read_rom(&self, address: u16) -> u8 {
+        let bank = if address <= FIXED_BANK_END { self.fixed_rom_bank() } else { self.switchable_rom_bank() };
+        return read_rom_bank(&self._rom, bank, address);
+    }
+    
+    fn write_rom(&mut self, address: u16, value: u8) {
+        match address {
+            0x0000..=RAM_ENABLE_END => self._ram_enabled = value & RAM_ENABLE_MASK == RAM_ENABLE_VALUE,
+            // Bank 0 can never be selected here, so asking for it gives bank 1
+            0x2000..=ROM_BANK_SELECT_END => self._rom_bank = match value & ROM_BANK_MASK {
+                0 => 1,
+                bank => bank,
+            },
+            0x4000..=UPPER_BANK_SELECT_END => self._upper_bank = value & UPPER_BANK_MASK,
+            _ => self._advanced_mode = value & MODE_MASK != 0,
+        }
+    }
+    
+    fn read_ram(&self, offset: u16) -> u8 {
+        if !self._ram_enabled {
+            return DISABLED_RAM_VALUE;
+        }
+        return match ram_index(&self._ram, self.ram_bank(), offset) {
+            Some(index) => self._ram[index],
+            None => DISABLED_RAM_VALUE,
+        };
+    }
+    
+    fn write_ram(&mut self, offset: u16, value: u8) {
+        if !self._ram_enabled {
+            return;
+        }
+        if let Some(index) = ram_index(&self._ram, self.ram_bank(), offset) {
+            self._ram[index] = value;
+        }
+    }
+    
+    fn ram(&self) -> &[u8] {
+        return &self._ram;
+    }
+    
+    fn ram_mut(&mut self) -> &mut [u8] {
+        return &mut self._ram;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::testing::build_test_rom;
+    
+    const TWO_MIB_ROM_CODE: u8 = 0x06;
+    const RAM_SIZE: usize = 0x8000;
+    
+    fn build_mbc1() -> Mbc1 {
+        return Mbc1::new(build_test_rom(0x03, TWO_MIB_ROM_CODE, 0x03), RAM_SIZE);
+    }
+    
+    #[test]
+    fn test_read_rom_starts_with_bank_one_switched_in() {
+        let mapper = build_mbc1();
+        
+        assert_eq!(mapper.read_rom(0x4000), 1);
+    }
+    
+    #[test]
+    fn test_write_rom_selects_rom_bank() {
+        let mut mapper = build_mbc1();
+        
+        mapper.write_rom(0x2000, 0x05);
+        
+        assert_eq!(mapper.read_rom(0x4000), 5);
+    }
+    
+    #[test]
+    fn test_write_rom_bank_zero_selects_bank_one() {
+        let mut mapper = build_mbc1();
+        
+        mapper.write_rom(0x2000, 0x00);
+        
+        assert_eq!(mapper.read_rom(0x4000), 1);
+    }
+    
+    #[test]
+    fn test_write_rom_bank_zero_with_upper_bits_selects_next_bank() {
+        let mut mapper = build_mbc1();
+        
+        mapper.write_rom(0x4000, 0x01);
+        mapper.write_rom(0x2000, 0x00);
+        
+        assert_eq!(mapper.read_rom(0x4000), 0x21);
+    }
+    
+    #[test]
+    fn test_read_rom_fixed_bank_follows_upper_bits_only_in_advanced_mode() {
+        let mut mapper = build_mbc1();
+        mapper.write_rom(0x4000, 0x02);
+        
+        let simple_mode_bank = mapper.read_rom(0x0000);
+        mapper.write_rom(0x6000, 0x01);
+        let advanced_mode_bank = mapper.read_rom(0x0000);
+        
+        assert_eq!(simple_mode_bank, 0x00);
+        assert_eq!(advanced_mode_bank, 0x40);
+    }
+    
+    #[test]
+    fn test_read_ram_disabled_returns_disabled_value() {
+        let mut mapper = build_mbc1();
+        
+        mapper.write_ram(0x0000, 0x12);
+        
+        assert_eq!(mapper.read_ram(0x0000), DISABLED_RAM_VALUE);
+    }
+    
+    #[test]
+    fn test_write_ram_enabled_stores_value() {
+        let mut mapper = build_mbc1();
+        mapper.write_rom(0x0000, 0x0A);
+        
+        mapper.write_ram(0x0010, 0x12);
+        
+        assert_eq!(mapper.read_ram(0x0010), 0x12);
+    }
+    
+    #[test]
+    fn test_write_ram_advanced_mode_switches_ram_bank() {
+        let mut mapper = build_mbc1();
+        mapper.write_rom(0x0000, 0x0A);
+        mapper.write_rom(0x6000, 0x01);
+        mapper.write_ram(0x0000, 0x11);
+        
+        mapper.write_rom(0x4000, 0x02);
+        mapper.write_ram(0x0000, 0x22);
+        let bank_two = mapper.read_ram(0x0000);
+        mapper.write_rom(0x4000, 0x00);
+        let bank_zero = mapper.read_ram(0x0000);
+        
+        assert_eq!(bank_two, 0x22);
+        assert_eq!(bank_zero, 0x11);
+    }
+    
+    #[test]
+    fn test_write_ram_simple_mode_ignores_upper_bits() {
+        let mut mapper = build_mbc1();
+        mapper.write_rom(0x0000, 0x0A);
+        mapper.write_ram(0x0000, 0x11);
+        
+        mapper.write_rom(0x4000, 0x02);
+        
+        assert_eq!(mapper.read_ram(0x0000), 0x11);
+    }
+}
@@ -0,0 +1,135 @@
+use super::mapper::{read_rom_bank, Mapper, DISABLED_RAM_VALUE};
+
+const REGISTER_SELECT_END: u16 = 0x3FFF;
+const FIXED_BANK_END: u16 = 0x3FFF;
+// Address bit 8 picks between the RAM enable and the ROM bank register
+const REGISTER_SELECT_BIT: u16 = 0x0100;
+
+const RAM_ENABLE_VALUE: u8 = 0x0A;
+const RAM_ENABLE_MASK: u8 = 0x0F;
+const ROM_BANK_MASK: u8 = 0x0F;
+
+// 512 half-bytes on the controller itself, repeated across the whole external RAM window
+const RAM_SIZE: usize = 0x200;
+const RAM_VALUE_MASK: u8 = 0x0F;
+// Only four data lines are connected, so the upper nibble floats high
+const UNCONNECTED_BITS: u8 = 0xF0;
+
+pub struct Mbc2 {
+    _rom: Vec<u8>,
+    _ram: [u8; RAM_SIZE],
+    _ram_enabled: bool,
+    _rom_bank: u8,
+}
+
+impl Mbc2 {
+    pub fn new(rom: Vec<u8>) -> Mbc2 {
+        return Mbc2 {
+            _rom: rom,
+            _ram: [0; RAM_SIZE],
+            _ram_enabled: false,
+            _rom_bank: 1,
+        };
+    }
+}
+
+impl Mapper for Mbc2 {
+    fn read_rom(&self, address: u16) -> u8 {
+        let bank = if address <= FIXED_BANK_END { 0 } else { self._rom_bank as usize };
+        return read_rom_bank(&self._rom, bank, address);
+    }
+    
+    fn write_rom(&mut self, address: u16, value: u8) {
+        if address > REGISTER_SELECT_END {
+            return;
+        }
+        if address & REGISTER_SELECT_BIT == 0 {
+            self._ram_enabled = value & RAM_ENABLE_MASK == RAM_ENABLE_VALUE;
+        } else {
+            self._rom_bank = match value & ROM_BANK_MASK {
+                0 => 1,
+                bank => bank,
+            };
+        }
+    }
+    
+    fn read_ram(&self, offset: u16) -> u8 {
+        if !self._ram_enabled {
+            return DISABLED_RAM_VALUE;
+        }
+        return self._ram[offset as usize % RAM_SIZE] | UNCONNECTED_BITS;
+    }
+    
+    fn write_ram(&mut self, offset: u16, value: u8) {
+        if self._ram_enabled {
+            self._ram[offset as usize % RAM_SIZE] = value & RAM_VALUE_MASK;
+        }
+    }
+    
+    fn ram(&self) -> &[u8] {
+        return &self._ram;
+    }
+    
+    fn ram_mut(&mut self) -> &mut [u8] {
+        return &mut self._ram;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::testing::build_test_rom;
+    
+    fn build_mbc2() -> Mbc2 {
+        let mut mapper = Mbc2::new(build_test_rom(0x06, 0x03, 0x00));
+        mapper.write_rom(0x0000, RAM_ENABLE_VALUE);
+        return mapper;
+    }
+    
+    #[test]
+    fn test_write_rom_with_bit_eight_selects_rom_bank() {
+        let mut mapper = build_mbc2();
+        
+        mapper.write_rom(0x2100, 0x0D);
+        
+        assert_eq!(mapper.read_rom(0x4000), 0x0D);
+    }
+    
+    #[test]
+    fn test_write_rom_bank_zero_selects_bank_one() {
+        let mut mapper = build_mbc2();
+        
+        mapper.write_rom(0x0100, 0x00);
+        
+        assert_eq!(mapper.read_rom(0x4000), 0x01);
+    }
+    
+    #[test]
+    fn test_write_rom_without_bit_eight_toggles_ram() {
+        let mut mapper = build_mbc2();
+        
+        mapper.write_rom(0x3E00, 0x00);
+        
+        assert_eq!(mapper.read_ram(0x0000), DISABLED_RAM_VALUE);
+        assert_eq!(mapper.read_rom(0x4000), 0x01);
+    }
+    
+    #[test]
+    fn test_read_ram_sets_upper_nibble() {
+        let mut mapper = build_mbc2();
+        
+        mapper.write_ram(0x0010, 0xA5);
+        
+        assert_eq!(mapper.read_ram(0x0010), 0xF5);
+    }
+    
+    #[test]
+    fn test_read_ram_mirrors_across_window() {
+        let mut mapper = build_mbc2();
+        
+        mapper.write_ram(0x0010, 0x07);
+        
+        assert_eq!(mapper.read_ram(0x0210), 0xF7);
+        assert_eq!(mapper.read_ram(0x1E10), 0xF7);
+    }
+}
@@ -0,0 +1,260 @@
+use super::clock::Clock;
+use super::mapper::{ram_index, read_rom_bank, Mapper, DISABLED_RAM_VALUE};
+use super::real_time_clock::{RealTimeClock, SECONDS_REGISTER, DAY_HIGH_REGISTER};
+
+const RAM_ENABLE_END: u16 = 0x1FFF;
+const ROM_BANK_SELECT_END: u16 = 0x3FFF;
+const RAM_BANK_SELECT_END: u16 = 0x5FFF;
+const FIXED_BANK_END: u16 = 0x3FFF;
+
+const RAM_ENABLE_VALUE: u8 = 0x0A;
+const RAM_ENABLE_MASK: u8 = 0x0F;
+const ROM_BANK_MASK: u8 = 0x7F;
+const LAST_RAM_BANK: u8 = 0x07;
+// Writing 0x00 and then 0x01 to 0x6000-0x7FFF latches the clock
+const LATCH_PREPARE_VALUE: u8 = 0x00;
+const LATCH_VALUE: u8 = 0x01;
+
+pub struct Mbc3 {
+    _rom: Vec<u8>,
+    _ram: Vec<u8>,
+    _clock: Option<RealTimeClock>,
+    _ram_enabled: bool,
+    _rom_bank: u8,
+    // Either a RAM bank or one of the clock registers
+    _ram_bank: u8,
+    _latch_prepared: bool,
+}
+
+impl Mbc3 {
+    pub fn new(rom: Vec<u8>, ram_size: usize) -> Mbc3 {
+        return Mbc3 {
+            _rom: rom,
+            _ram: vec![0; ram_size],
+            _clock: None,
+            _ram_enabled: false,
+            _rom_bank: 1,
+            _ram_bank: 0,
+            _latch_prepared: false,
+        };
+    }
+    
+    pub fn new_with_clock(rom: Vec<u8>, ram_size: usize, clock: Box<dyn Clock>) -> Mbc3 {
+        let mut mapper = Mbc3::new(rom, ram_size);
+        mapper._clock = Some(RealTimeClock::new(clock));
+        return mapper;
+    }
+    
+    fn selected_clock_register(&self) -> Option<u8> {
+        return match self._ram_bank {
+            SECONDS_REGISTER..=DAY_HIGH_REGISTER if self._clock.is_some() => Some(self._ram_bank),
+            _ => None,
+        };
+    }
+    
+    fn selected_ram_index(&self, offset: u16) -> Option<usize> {
+        if self._ram_bank > LAST_RAM_BANK {
+            return None;
+        }
+        return ram_index(&self._ram, self._ram_bank as usize, offset);
+    }
+}
+
+impl Mapper for Mbc3 {
+    fn read_rom(&self, address: u16) -> u8 {
+        let bank = if address <= FIXED_BANK_END { 0 } else { self._rom_bank as usize };
+        return read_rom_bank(&self._rom, bank, address);
+    }
+    
+    fn write_rom(&mut self, address: u16, value: u8) {
+        match address {
+            0x0000..=RAM_ENABLE_END => self._ram_enabled = value & RAM_ENABLE_MASK == RAM_ENABLE_VALUE,
+            0x2000..=ROM_BANK_SELECT_END => self._rom_bank = match value & ROM_BANK_MASK {
+                0 => 1,
+                bank => bank,
+            },
+            0x4000..=RAM_BANK_SELECT_END => self._ram_bank = value,
+            _ => {
+                if self._latch_prepared && value == LATCH_VALUE {
+                    if let Some(clock) = self._clock.as_mut() {
+                        clock.latch();
+                    }
+                }
+                self._latch_prepared = value == LATCH_PREPARE_VALUE;
+            },
+        }
+    }
+    
+    fn read_ram(&self, offset: u16) -> u8 {
+        if !self._ram_enabled {
+            return DISABLED_RAM_VALUE;
+        }
+        if let (Some(register), Some(clock)) = (self.selected_clock_register(), self._clock.as_ref()) {
+            return clock.read_register(register);
+        }
+        return match self.selected_ram_index(offset) {
+            Some(index) => self._ram[index],
+            None => DISABLED_RAM_VALUE,
+        };
+    }
+    
+    fn write_ram(&mut self, offset: u16, value: u8) {
+        if !self._ram_enabled {
+            return;
+        }
+        if let Some(register) = self.selected_clock_register() {
+            if let Some(clock) = self._clock.as_mut() {
+                clock.write_register(register, value);
+            }
+            return;
+        }
+        if let Some(index) = self.selected_ram_index(offset) {
+            self._ram[index] = value;
+        }
+    }
+    
+    fn ram(&self) -> &[u8] {
+        return &self._ram;
+    }
+    
+    fn ram_mut(&mut self) -> &mut [u8] {
+        return &mut self._ram;
+    }
+    
+    // The clock state follows the RAM contents
+    fn save_data(&self) -> Vec<u8> {
+        let mut data = self._ram.clone();
+        if let Some(clock) = self._clock.as_ref() {
+            data.extend_from_slice(&clock.save_state());
+        }
+        return data;
+    }
+    
+    fn load_save_data(&mut self, data: &[u8]) {
+        let length = self._ram.len().min(data.len());
+        self._ram[..length].copy_from_slice(&data[..length]);
+        if let Some(clock) = self._clock.as_mut() {
+            clock.load_state(&data[length..]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::testing::{build_test_rom, FakeClock};
+    use super::super::real_time_clock::MINUTES_REGISTER;
+    use crate::boxed;
+    
+    use std::rc::Rc;
+    use std::cell::Cell;
+    
+    const RAM_SIZE: usize = 0x8000;
+    
+    fn build_mbc3() -> (Mbc3, Rc<Cell<u64>>) {
+        let now = Rc::new(Cell::new(0));
+        let rom = build_test_rom(0x10, 0x06, 0x03);
+        let mut mapper = Mbc3::new_with_clock(rom, RAM_SIZE, boxed!(FakeClock {now: now.clone()}));
+        mapper.write_rom(0x0000, RAM_ENABLE_VALUE);
+        return (mapper, now);
+    }
+    
+    fn latch(mapper: &mut Mbc3) {
+        mapper.write_rom(0x6000, LATCH_PREPARE_VALUE);
+        mapper.write_rom(0x6000, LATCH_VALUE);
+    }
+    
+    #[test]
+    fn test_write_rom_selects_seven_bit_rom_bank() {
+        let (mut mapper, _now) = build_mbc3();
+        
+        mapper.write_rom(0x2000, 0x45);
+        
+        assert_eq!(mapper.read_rom(0x4000), 0x45);
+    }
+    
+    #[test]
+    fn test_write_rom_bank_zero_selects_bank_one() {
+        let (mut mapper, _now) = build_mbc3();
+        
+        mapper.write_rom(0x2000, 0x00);
+        
+        assert_eq!(mapper.read_rom(0x4000), 0x01);
+    }
+    
+    #[test]
+    fn test_write_ram_switches_ram_bank() {
+        let (mut mapper, _now) = build_mbc3();
+        mapper.write_ram(0x0000, 0x11);
+        
+        mapper.write_rom(0x4000, 0x03);
+        mapper.write_ram(0x0000, 0x33);
+        let bank_three = mapper.read_ram(0x0000);
+        mapper.write_rom(0x4000, 0x00);
+        let bank_zero = mapper.read_ram(0x0000);
+        
+        assert_eq!(bank_three, 0x33);
+        assert_eq!(bank_zero, 0x11);
+    }
+    
+    #[test]
+    fn test_read_ram_clock_register_returns_latched_time() {
+        let (mut mapper, now) = build_mbc3();
+        mapper.write_rom(0x4000, SECONDS_REGISTER);
+        now.set(42);
+        
+        latch(&mut mapper);
+        
+        assert_eq!(mapper.read_ram(0x0000), 42);
+    }
+    
+    #[test]
+    fn test_latch_requires_zero_then_one() {
+        let (mut mapper, now) = build_mbc3();
+        mapper.write_rom(0x4000, SECONDS_REGISTER);
+        now.set(42);
+        
+        mapper.write_rom(0x6000, LATCH_VALUE);
+        
+        assert_eq!(mapper.read_ram(0x0000), 0);
+    }
+    
+    #[test]
+    fn test_write_ram_clock_register_sets_clock() {
+        let (mut mapper, _now) = build_mbc3();
+        mapper.write_rom(0x4000, MINUTES_REGISTER);
+        
+        mapper.write_ram(0x0000, 12);
+        latch(&mut mapper);
+        
+        assert_eq!(mapper.read_ram(0x0000), 12);
+    }
+    
+    #[test]
+    fn test_load_save_data_restores_ram_and_clock() {
+        let (mut mapper, now) = build_mbc3();
+        mapper.write_ram(0x0000, 0x5A);
+        mapper.write_rom(0x4000, MINUTES_REGISTER);
+        mapper.write_ram(0x0000, 30);
+        let data = mapper.save_data();
+        let (mut restored, restored_now) = build_mbc3();
+        restored_now.set(now.get());
+        
+        restored.load_save_data(&data);
+        latch(&mut restored);
+        let ram_value = restored.read_ram(0x0000);
+        restored.write_rom(0x4000, MINUTES_REGISTER);
+        
+        assert_eq!(ram_value, 0x5A);
+        assert_eq!(restored.read_ram(0x0000), 30);
+    }
+    
+    #[test]
+    fn test_read_ram_disabled_returns_disabled_value() {
+        let (mut mapper, _now) = build_mbc3();
+        
+        mapper.write_rom(0x0000, 0x00);
+        
+        assert_eq!(mapper.read_ram(0x0000), DISABLED_RAM_VALUE);
+    }
+}
@@ -0,0 +1,181 @@
+use super::mapper::{ram_index, read_rom_bank, Mapper, DISABLED_RAM_VALUE};
+
+const RAM_ENABLE_END: u16 = 0x1FFF;
+const ROM_BANK_LOW_SELECT_END: u16 = 0x2FFF;
+const ROM_BANK_HIGH_SELECT_END: u16 = 0x3FFF;
+const RAM_BANK_SELECT_END: u16 = 0x5FFF;
+const FIXED_BANK_END: u16 = 0x3FFF;
+
+// Unlike the older controllers, MBC5 compares the whole byte
+const RAM_ENABLE_VALUE: u8 = 0x0A;
+const ROM_BANK_HIGH_MASK: u16 = 0x01;
+const RAM_BANK_MASK: u8 = 0x0F;
+// Rumble cartridges wire this RAM bank line to the motor instead
+const RUMBLE_MOTOR_BIT: u8 = 0x08;
+
+pub struct Mbc5 {
+    _rom: Vec<u8>,
+    _ram: Vec<u8>,
+    _has_rumble: bool,
+    _ram_enabled: bool,
+    // 9 bits wide, and bank 0 is allowed
+    _rom_bank: u16,
+    _ram_bank: u8,
+    _rumble_motor_on: bool,
+}
+
+impl Mbc5 {
+    pub fn new(rom: Vec<u8>, ram_size: usize) -> Mbc5 {
+        return Mbc5 {
+            _rom: rom,
+            _ram: vec![0; ram_size],
+            _has_rumble: false,
+            _ram_enabled: false,
+            _rom_bank: 1,
+            _ram_bank: 0,
+            _rumble_motor_on: false,
+        };
+    }
+    
+    pub fn new_with_rumble(rom: Vec<u8>, ram_size: usize) -> Mbc5 {
+        let mut mapper = Mbc5::new(rom, ram_size);
+        mapper._has_rumble = true;
+        return mapper;
+    }
+}
+
+impl Mapper for Mbc5 {
+    fn read_rom(&self, address: u16) -> u8 {
+        let bank = if address <= FIXED_BANK_END { 0 } else { self._rom_bank as usize };
+        return read_rom_bank(&self._rom, bank, address);
+    }
+    
+    fn write_rom(&mut self, address: u16, value: u8) {
+        match address {
+            0x0000..=RAM_ENABLE_END => self._ram_enabled = value == RAM_ENABLE_VALUE,
+            0x2000..=ROM_BANK_LOW_SELECT_END => self._rom_bank = (self._rom_bank & 0x100) | value as u16,
+            0x3000..=ROM_BANK_HIGH_SELECT_END => self._rom_bank = (self._rom_bank & 0xFF) | ((value as u16 & ROM_BANK_HIGH_MASK) << 8),
+            0x4000..=RAM_BANK_SELECT_END => {
+                if self._has_rumble {
+                    self._rumble_motor_on = value & RUMBLE_MOTOR_BIT != 0;
+                    self._ram_bank = value & RAM_BANK_MASK & !RUMBLE_MOTOR_BIT;
+                } else {
+                    self._ram_bank = value & RAM_BANK_MASK;
+                }
+            },
+            _ => {},
+        }
+    }
+    
+    fn read_ram(&self, offset: u16) -> u8 {
+        if !self._ram_enabled {
+            return DISABLED_RAM_VALUE;
+        }
+        return match ram_index(&self._ram, self._ram_bank as usize, offset) {
+            Some(index) => self._ram[index],
+            None => DISABLED_RAM_VALUE,
+        };
+    }
+    
+    fn write_ram(&mut self, offset: u16, value: u8) {
+        if !self._ram_enabled {
+            return;
+        }
+        if let Some(index) = ram_index(&self._ram, self._ram_bank as usize, offset) {
+            self._ram[index] = value;
+        }
+    }
+    
+    fn is_rumble_motor_on(&self) -> bool {
+        return self._rumble_motor_on;
+    }
+    
+    fn ram(&self) -> &[u8] {
+        return &self._ram;
+    }
+    
+    fn ram_mut(&mut self) -> &mut [u8] {
+        return &mut self._ram;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::testing::build_test_rom;
+    
+    // 512 banks, so the ninth bank bit has somewhere to go
+    const EIGHT_MIB_ROM_CODE: u8 = 0x08;
+    const RAM_SIZE: usize = 0x20000;
+    
+    fn build_small_mbc5() -> Mbc5 {
+        let mut mapper = Mbc5::new(build_test_rom(0x1B, 0x02, 0x04), RAM_SIZE);
+        mapper.write_rom(0x0000, RAM_ENABLE_VALUE);
+        return mapper;
+    }
+    
+    #[test]
+    fn test_write_rom_selects_nine_bit_rom_bank() {
+        let mut mapper = Mbc5::new(build_test_rom(0x19, EIGHT_MIB_ROM_CODE, 0x00), 0);
+        
+        mapper.write_rom(0x2000, 0x23);
+        mapper.write_rom(0x3000, 0x01);
+        
+        assert_eq!(mapper.read_rom(0x4000), 0x23);
+        assert_eq!(mapper.read_rom(0x4001), 0x01);
+    }
+    
+    #[test]
+    fn test_write_rom_bank_zero_selects_bank_zero() {
+        let mut mapper = build_small_mbc5();
+        
+        mapper.write_rom(0x2000, 0x00);
+        
+        assert_eq!(mapper.read_rom(0x4000), 0x00);
+    }
+    
+    #[test]
+    fn test_write_ram_uses_sixteen_banks() {
+        let mut mapper = build_small_mbc5();
+        mapper.write_ram(0x0000, 0x11);
+        
+        mapper.write_rom(0x4000, 0x0F);
+        mapper.write_ram(0x0000, 0xFF);
+        let bank_fifteen = mapper.read_ram(0x0000);
+        mapper.write_rom(0x4000, 0x00);
+        let bank_zero = mapper.read_ram(0x0000);
+        
+        assert_eq!(bank_fifteen, 0xFF);
+        assert_eq!(bank_zero, 0x11);
+    }
+    
+    #[test]
+    fn test_ram_enable_needs_exact_value() {
+        let mut mapper = build_small_mbc5();
+        
+        mapper.write_rom(0x0000, 0x1A);
+        
+        assert_eq!(mapper.read_ram(0x0000), DISABLED_RAM_VALUE);
+    }
+    
+    #[test]
+    fn test_rumble_bit_drives_motor_instead_of_ram_bank() {
+        let mut mapper = Mbc5::new_with_rumble(build_test_rom(0x1E, 0x02, 0x03), 0x8000);
+        mapper.write_rom(0x0000, RAM_ENABLE_VALUE);
+        mapper.write_ram(0x0000, 0x11);
+        
+        mapper.write_rom(0x4000, RUMBLE_MOTOR_BIT);
+        
+        assert_eq!(mapper.is_rumble_motor_on(), true);
+        assert_eq!(mapper.read_ram(0x0000), 0x11);
+    }
+    
+    #[test]
+    fn test_rumble_bit_without_rumble_selects_ram_bank() {
+        let mut mapper = build_small_mbc5();
+        
+        mapper.write_rom(0x4000, RUMBLE_MOTOR_BIT);
+        
+        assert_eq!(mapper.is_rumble_motor_on(), false);
+    }
+}
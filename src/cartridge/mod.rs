@@ -0,0 +1,22 @@
+pub mod cartridge;
+pub mod cartridge_error;
+pub mod cartridge_header;
+pub mod mapper;
+
+mod clock;
+mod mbc1;
+mod mbc2;
+mod mbc3;
+mod mbc5;
+mod real_time_clock;
+mod rom_only;
+mod save_file;
+
+pub use cartridge::Cartridge;
+pub use cartridge_error::CartridgeError;
+pub use mapper::{build_mapper, Mapper};
+pub use rom_only::RomOnly;
+pub use save_file::SaveFile;
+
+#[cfg(test)]
+pub mod testing;
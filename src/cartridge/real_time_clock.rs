@@ -0,0 +1,296 @@
+use super::clock::Clock;
+
+pub const SECONDS_REGISTER: u8 = 0x08;
+pub const MINUTES_REGISTER: u8 = 0x09;
+pub const HOURS_REGISTER: u8 = 0x0A;
+pub const DAY_LOW_REGISTER: u8 = 0x0B;
+pub const DAY_HIGH_REGISTER: u8 = 0x0C;
+
+// Five live registers, five latched registers and a timestamp, the layout other emulators use
+const SAVE_STATE_SIZE: usize = 48;
+const SAVE_REGISTER_SIZE: usize = 4;
+const SAVE_TIMESTAMP_START: usize = 40;
+
+const SECONDS_PER_MINUTE: u64 = 60;
+const MINUTES_PER_HOUR: u64 = 60;
+const HOURS_PER_DAY: u64 = 24;
+// The day counter is 9 bits wide
+const DAYS_PER_CYCLE: u64 = 0x200;
+
+const DAY_HIGH_BIT: u8 = 0x01;
+const HALT_BIT: u8 = 0x40;
+const DAY_CARRY_BIT: u8 = 0x80;
+const SIXTY_MASK: u8 = 0x3F;
+const HOURS_MASK: u8 = 0x1F;
+
+// The MBC3 clock: live counters that tick with the injected clock, and a latched copy the CPU reads
+pub struct RealTimeClock {
+    _clock: Box<dyn Clock>,
+    _last_update: u64,
+    _seconds: u64,
+    _minutes: u64,
+    _hours: u64,
+    _days: u64,
+    _halted: bool,
+    _day_carry: bool,
+    _latched: [u8; 5],
+}
+
+impl RealTimeClock {
+    pub fn new(clock: Box<dyn Clock>) -> RealTimeClock {
+        let now = clock.now_seconds();
+        return RealTimeClock {
+            _clock: clock,
+            _last_update: now,
+            _seconds: 0,
+            _minutes: 0,
+            _hours: 0,
+            _days: 0,
+            _halted: false,
+            _day_carry: false,
+            _latched: [0; 5],
+        };
+    }
+    
+    // Copies the live counters into the registers the CPU can read
+    pub fn latch(&mut self) {
+        self.update();
+        self._latched = [
+            self._seconds as u8,
+            self._minutes as u8,
+            self._hours as u8,
+            self._days as u8,
+            self.day_high(),
+        ];
+    }
+    
+    pub fn read_register(&self, register: u8) -> u8 {
+        return self._latched[(register - SECONDS_REGISTER) as usize];
+    }
+    
+    pub fn write_register(&mut self, register: u8, value: u8) {
+        self.update();
+        match register {
+            SECONDS_REGISTER => self._seconds = (value & SIXTY_MASK) as u64,
+            MINUTES_REGISTER => self._minutes = (value & SIXTY_MASK) as u64,
+            HOURS_REGISTER => self._hours = (value & HOURS_MASK) as u64,
+            DAY_LOW_REGISTER => self._days = (self._days & 0x100) | value as u64,
+            _ => {
+                self._days = (self._days & 0xFF) | (((value & DAY_HIGH_BIT) as u64) << 8);
+                self._halted = value & HALT_BIT != 0;
+                self._day_carry = value & DAY_CARRY_BIT != 0;
+            },
+        }
+    }
+    
+    pub fn save_state(&self) -> Vec<u8> {
+        let live = [self._seconds as u8, self._minutes as u8, self._hours as u8, self._days as u8, self.day_high()];
+        let mut state = Vec::with_capacity(SAVE_STATE_SIZE);
+        for value in live.iter().chain(self._latched.iter()) {
+            state.extend_from_slice(&(*value as u32).to_le_bytes());
+        }
+        state.extend_from_slice(&self._last_update.to_le_bytes());
+        return state;
+    }
+    
+    // Saves without a clock block leave the clock as it is
+    pub fn load_state(&mut self, state: &[u8]) {
+        if state.len() < SAVE_STATE_SIZE {
+            return;
+        }
+        let registers: Vec<u8> = state[..SAVE_TIMESTAMP_START].chunks(SAVE_REGISTER_SIZE).map(|chunk| chunk[0]).collect();
+        self._seconds = registers[0] as u64;
+        self._minutes = registers[1] as u64;
+        self._hours = registers[2] as u64;
+        self._days = registers[3] as u64 | (((registers[4] & DAY_HIGH_BIT) as u64) << 8);
+        self._halted = registers[4] & HALT_BIT != 0;
+        self._day_carry = registers[4] & DAY_CARRY_BIT != 0;
+        self._latched.copy_from_slice(&registers[5..]);
+        
+        let mut timestamp = [0; 8];
+        timestamp.copy_from_slice(&state[SAVE_TIMESTAMP_START..SAVE_STATE_SIZE]);
+        self._last_update = u64::from_le_bytes(timestamp);
+        // A halted clock does not catch up on the time the console was off
+        if self._halted {
+            self._last_update = self._clock.now_seconds();
+        }
+    }
+    
+    fn day_high(&self) -> u8 {
+        let mut value = (self._days >> 8) as u8 & DAY_HIGH_BIT;
+        if self._halted {
+            value |= HALT_BIT;
+        }
+        if self._day_carry {
+            value |= DAY_CARRY_BIT;
+        }
+        return value;
+    }
+    
+    // Moves the counters forward by however long the clock ran since the last update
+    fn update(&mut self) {
+        let now = self._clock.now_seconds();
+        let elapsed = now.saturating_sub(self._last_update);
+        self._last_update = now;
+        if self._halted || elapsed == 0 {
+            return;
+        }
+        
+        let (seconds, minutes) = advance_counter(self._seconds, elapsed, SECONDS_PER_MINUTE, SIXTY_MASK as u64 + 1);
+        let (minutes, hours) = advance_counter(self._minutes, minutes, MINUTES_PER_HOUR, SIXTY_MASK as u64 + 1);
+        let (hours, days) = advance_counter(self._hours, hours, HOURS_PER_DAY, HOURS_MASK as u64 + 1);
+        self._seconds = seconds;
+        self._minutes = minutes;
+        self._hours = hours;
+        let days = self._days + days;
+        if days >= DAYS_PER_CYCLE {
+            self._day_carry = true;
+        }
+        self._days = days % DAYS_PER_CYCLE;
+    }
+}
+
+// Counts a field forward, returning its new value and how many times it rolled over into the next field.
+// A value written past the rollover keeps counting up to the register's limit and wraps to 0 without a carry.
+fn advance_counter(value: u64, amount: u64, rollover: u64, limit: u64) -> (u64, u64) {
+    if value < rollover {
+        let total = value + amount;
+        return (total % rollover, total / rollover);
+    }
+    let steps_to_wrap = limit - value;
+    if amount < steps_to_wrap {
+        return (value + amount, 0);
+    }
+    let amount = amount - steps_to_wrap;
+    return (amount % rollover, amount / rollover);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::testing::FakeClock;
+    use crate::boxed;
+    
+    use std::rc::Rc;
+    use std::cell::Cell;
+    
+    const SECONDS_PER_HOUR: u64 = MINUTES_PER_HOUR * SECONDS_PER_MINUTE;
+    const SECONDS_PER_DAY: u64 = HOURS_PER_DAY * SECONDS_PER_HOUR;
+    
+    fn build_clock() -> (RealTimeClock, Rc<Cell<u64>>) {
+        let now = Rc::new(Cell::new(1000));
+        let clock = RealTimeClock::new(boxed!(FakeClock {now: now.clone()}));
+        return (clock, now);
+    }
+    
+    #[test]
+    fn test_latch_captures_elapsed_time() {
+        let (mut clock, now) = build_clock();
+        now.set(now.get() + 2 * SECONDS_PER_DAY + 3 * SECONDS_PER_HOUR + 4 * SECONDS_PER_MINUTE + 5);
+        
+        clock.latch();
+        
+        assert_eq!(clock.read_register(SECONDS_REGISTER), 5);
+        assert_eq!(clock.read_register(MINUTES_REGISTER), 4);
+        assert_eq!(clock.read_register(HOURS_REGISTER), 3);
+        assert_eq!(clock.read_register(DAY_LOW_REGISTER), 2);
+    }
+    
+    #[test]
+    fn test_read_register_keeps_latched_value_while_time_passes() {
+        let (mut clock, now) = build_clock();
+        clock.latch();
+        
+        now.set(now.get() + 30);
+        
+        assert_eq!(clock.read_register(SECONDS_REGISTER), 0);
+    }
+    
+    #[test]
+    fn test_halted_clock_does_not_advance() {
+        let (mut clock, now) = build_clock();
+        clock.write_register(DAY_HIGH_REGISTER, HALT_BIT);
+        
+        now.set(now.get() + 30);
+        clock.latch();
+        
+        assert_eq!(clock.read_register(SECONDS_REGISTER), 0);
+        assert_eq!(clock.read_register(DAY_HIGH_REGISTER), HALT_BIT);
+    }
+    
+    #[test]
+    fn test_day_counter_overflow_sets_carry() {
+        let (mut clock, now) = build_clock();
+        clock.write_register(DAY_LOW_REGISTER, 0xFF);
+        clock.write_register(DAY_HIGH_REGISTER, DAY_HIGH_BIT);
+        
+        now.set(now.get() + SECONDS_PER_DAY);
+        clock.latch();
+        
+        assert_eq!(clock.read_register(DAY_LOW_REGISTER), 0x00);
+        assert_eq!(clock.read_register(DAY_HIGH_REGISTER), DAY_CARRY_BIT);
+    }
+    
+    #[test]
+    fn test_load_state_catches_up_on_time_since_save() {
+        let (mut clock, now) = build_clock();
+        clock.write_register(HOURS_REGISTER, 5);
+        let state = clock.save_state();
+        let (mut restored, restored_now) = build_clock();
+        restored_now.set(now.get() + SECONDS_PER_HOUR);
+        
+        restored.load_state(&state);
+        restored.latch();
+        
+        assert_eq!(state.len(), SAVE_STATE_SIZE);
+        assert_eq!(restored.read_register(HOURS_REGISTER), 6);
+    }
+    
+    #[test]
+    fn test_write_register_sets_counter() {
+        let (mut clock, now) = build_clock();
+        clock.write_register(MINUTES_REGISTER, 59);
+        
+        now.set(now.get() + SECONDS_PER_MINUTE);
+        clock.latch();
+        
+        assert_eq!(clock.read_register(MINUTES_REGISTER), 0);
+        assert_eq!(clock.read_register(HOURS_REGISTER), 1);
+    }
+    
+    #[test]
+    fn test_invalid_seconds_wrap_without_carry() {
+        let (mut clock, now) = build_clock();
+        clock.write_register(SECONDS_REGISTER, 0x3F);
+        
+        now.set(now.get() + 1);
+        clock.latch();
+        
+        assert_eq!(clock.read_register(SECONDS_REGISTER), 0);
+        assert_eq!(clock.read_register(MINUTES_REGISTER), 0);
+    }
+    
+    #[test]
+    fn test_invalid_hours_wrap_without_day_carry() {
+        let (mut clock, now) = build_clock();
+        clock.write_register(HOURS_REGISTER, 31);
+        
+        now.set(now.get() + SECONDS_PER_HOUR);
+        clock.latch();
+        
+        assert_eq!(clock.read_register(HOURS_REGISTER), 0);
+        assert_eq!(clock.read_register(DAY_LOW_REGISTER), 0);
+    }
+    
+    #[test]
+    fn test_invalid_seconds_count_to_limit_then_run_normally() {
+        let (mut clock, now) = build_clock();
+        clock.write_register(SECONDS_REGISTER, 62);
+        
+        now.set(now.get() + 2 + 61);
+        clock.latch();
+        
+        assert_eq!(clock.read_register(SECONDS_REGISTER), 1);
+        assert_eq!(clock.read_register(MINUTES_REGISTER), 1);
+    }
+}
@@ -0,0 +1,74 @@
+use super::mapper::{ram_index, Mapper, DISABLED_RAM_VALUE, ROM_BANK_SIZE};
+
+const ROM_SIZE: usize = 2 * ROM_BANK_SIZE;
+
+// A cartridge with at most 32 KiB of ROM wired straight to the bus
+pub struct RomOnly {
+    _rom: Vec<u8>,
+    _ram: Vec<u8>,
+}
+
+impl RomOnly {
+    pub fn new(mut rom: Vec<u8>, ram_size: usize) -> RomOnly {
+        rom.resize(ROM_SIZE, 0);
+        return RomOnly {_rom: rom, _ram: vec![0; ram_size]};
+    }
+}
+
+impl Mapper for RomOnly {
+    fn read_rom(&self, address: u16) -> u8 {
+        return self._rom[address as usize];
+    }
+    
+    fn write_rom(&mut self, _address: u16, _value: u8) {}
+    
+    fn read_ram(&self, offset: u16) -> u8 {
+        return match ram_index(&self._ram, 0, offset) {
+            Some(index) => self._ram[index],
+            None => DISABLED_RAM_VALUE,
+        };
+    }
+    
+    fn write_ram(&mut self, offset: u16, value: u8) {
+        if let Some(index) = ram_index(&self._ram, 0, offset) {
+            self._ram[index] = value;
+        }
+    }
+    
+    fn ram(&self) -> &[u8] {
+        return &self._ram;
+    }
+    
+    fn ram_mut(&mut self) -> &mut [u8] {
+        return &mut self._ram;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_new_pads_short_rom() {
+        let mapper = RomOnly::new(vec![0x01, 0x02], 0);
+        
+        assert_eq!(mapper.read_rom(0x0001), 0x02);
+        assert_eq!(mapper.read_rom(0x7FFF), 0x00);
+    }
+    
+    #[test]
+    fn test_write_rom_is_ignored() {
+        let mut mapper = RomOnly::new(vec![0x01, 0x02], 0);
+        
+        mapper.write_rom(0x0000, 0xFF);
+        
+        assert_eq!(mapper.read_rom(0x0000), 0x01);
+    }
+    
+    #[test]
+    fn test_read_ram_without_ram_returns_disabled_value() {
+        let mapper = RomOnly::new(vec![], 0);
+        
+        assert_eq!(mapper.read_ram(0x0000), DISABLED_RAM_VALUE);
+    }
+}
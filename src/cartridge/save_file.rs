@@ -0,0 +1,83 @@
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+const SAVE_EXTENSION: &str = "sav";
+const TEMPORARY_EXTENSION: &str = "sav.tmp";
+
+// The <rom>.sav file that keeps battery-backed RAM between runs
+pub struct SaveFile {
+    _path: PathBuf,
+    _last_written: Vec<u8>,
+}
+
+impl SaveFile {
+    pub fn for_rom(rom_path: &Path) -> SaveFile {
+        return SaveFile {_path: rom_path.with_extension(SAVE_EXTENSION), _last_written: Vec::new()};
+    }
+    
+    pub fn path(&self) -> &Path {
+        return &self._path;
+    }
+    
+    // A missing file just means the game has never been saved
+    pub fn load(&mut self) -> io::Result<Option<Vec<u8>>> {
+        return match fs::read(&self._path) {
+            Ok(data) => {
+                self._last_written = data.clone();
+                Ok(Some(data))
+            },
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(error),
+        };
+    }
+    
+    // Writes a temporary file next to the save and renames it over, so a crash leaves the old save intact
+    pub fn write(&mut self, data: &[u8]) -> io::Result<()> {
+        if data == self._last_written.as_slice() {
+            return Ok(());
+        }
+        
+        let temporary_path = self._path.with_extension(TEMPORARY_EXTENSION);
+        let mut file = File::create(&temporary_path)?;
+        file.write_all(data)?;
+        file.sync_all()?;
+        fs::rename(&temporary_path, &self._path)?;
+        
+        self._last_written = data.to_vec();
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::testing::build_temp_rom_path;
+    
+    #[test]
+    fn test_for_rom_replaces_extension() {
+        let save_file = SaveFile::for_rom(Path::new("games/tetris.gb"));
+        
+        assert_eq!(save_file.path(), Path::new("games/tetris.sav"));
+    }
+    
+    #[test]
+    fn test_load_missing_file_returns_none() {
+        let mut save_file = SaveFile::for_rom(&build_temp_rom_path("missing"));
+        
+        assert_eq!(save_file.load().unwrap(), None);
+    }
+    
+    #[test]
+    fn test_write_then_load_returns_data() {
+        let rom_path = build_temp_rom_path("round-trip");
+        let mut save_file = SaveFile::for_rom(&rom_path);
+        
+        save_file.write(&[0x01, 0x02, 0x03]).unwrap();
+        let data = SaveFile::for_rom(&rom_path).load().unwrap();
+        fs::remove_file(save_file.path()).unwrap();
+        
+        assert_eq!(data, Some(vec![0x01, 0x02, 0x03]));
+        assert_eq!(save_file.path().with_extension(TEMPORARY_EXTENSION).exists(), false);
+    }
+}
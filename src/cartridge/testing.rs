@@ -0,0 +1,47 @@
+use super::cartridge_header::{compute_global_checksum, compute_header_checksum, GLOBAL_CHECKSUM_ADDRESS, HEADER_CHECKSUM_ADDRESS};
+use super::clock::Clock;
+
+use std::env;
+use std::path::PathBuf;
+use std::process;
+use std::rc::Rc;
+use std::cell::Cell;
+
+const TITLE: &[u8] = b"TEST";
+const TITLE_ADDRESS: usize = 0x0134;
+const CARTRIDGE_TYPE_ADDRESS: usize = 0x0147;
+const ROM_SIZE_ADDRESS: usize = 0x0148;
+const RAM_SIZE_ADDRESS: usize = 0x0149;
+
+// A ROM image with valid checksums where every bank starts with its own little-endian bank number
+pub fn build_test_rom(cartridge_type: u8, rom_size_code: u8, ram_size_code: u8) -> Vec<u8> {
+    let mut rom = vec![0; 0x8000 << rom_size_code];
+    for (bank, chunk) in rom.chunks_mut(0x4000).enumerate() {
+        chunk[0] = bank as u8;
+        chunk[1] = (bank >> 8) as u8;
+    }
+    rom[TITLE_ADDRESS..TITLE_ADDRESS + TITLE.len()].copy_from_slice(TITLE);
+    rom[CARTRIDGE_TYPE_ADDRESS] = cartridge_type;
+    rom[ROM_SIZE_ADDRESS] = rom_size_code;
+    rom[RAM_SIZE_ADDRESS] = ram_size_code;
+    rom[HEADER_CHECKSUM_ADDRESS] = compute_header_checksum(&rom);
+    let global_checksum = compute_global_checksum(&rom);
+    rom[GLOBAL_CHECKSUM_ADDRESS..GLOBAL_CHECKSUM_ADDRESS + 2].copy_from_slice(&global_checksum.to_be_bytes());
+    return rom;
+}
+
+// Where a test can put a ROM image, unique to the test name and the running process
+pub fn build_temp_rom_path(name: &str) -> PathBuf {
+    return env::temp_dir().join(format!("kaoboy-{}-{}.gb", name, process::id()));
+}
+
+// A clock the test moves forward by hand
+pub struct FakeClock {
+    pub now: Rc<Cell<u64>>,
+}
+
+impl Clock for FakeClock {
+    fn now_seconds(&self) -> u64 {
+        return self.now.get();
+    }
+}
@@ -1,19 +1,38 @@
+use super::cpu_state::CpuState;
+use super::decode_error::DecodeError;
 use super::instruction_context::InstructionContext;
 use super::program_counter::ProgramCounter;
 use super::stack::Stack;
 use super::registers::Registers;
+use super::trace::{TraceRecord, TraceSink, TRACE_BYTES};
 use crate::emulator::Memory;
 use crate::rc_refcell;
 
 use std::rc::Rc;
 use std::cell::RefCell;
 
+const INTERRUPT_FLAG_ADDRESS: u16 = 0xFF0F;
+const INTERRUPT_ENABLE_ADDRESS: u16 = 0xFFFF;
+const INTERRUPT_MASK: u8 = 0x1F;
+const JOYPAD_INTERRUPT_MASK: u8 = 0x10;
+const T_CYCLES_PER_M_CYCLE: u16 = 4;
+
+// DMG register values once the boot ROM hands over to the cartridge
+const POST_BOOT_AF: u16 = 0x01B0;
+const POST_BOOT_BC: u16 = 0x0013;
+const POST_BOOT_DE: u16 = 0x00D8;
+const POST_BOOT_HL: u16 = 0x014D;
+const POST_BOOT_STACK_POINTER: u16 = 0xFFFE;
+const POST_BOOT_PROGRAM_COUNTER: u16 = 0x0100;
+
 pub struct Cpu {
     pub _counter: Rc<RefCell<ProgramCounter>>,
     pub _registers: Rc<RefCell<Registers>>,
     pub _stack: Rc<RefCell<Stack>>,
+    pub _state: Rc<RefCell<CpuState>>,
     
     _context: InstructionContext,
+    _trace_sink: Option<Rc<RefCell<dyn TraceSink>>>,
 }
 
 impl Cpu {
@@ -21,24 +40,130 @@ impl Cpu {
         let program = rc_refcell!(ProgramCounter::new(memory.clone()));
         let registers = rc_refcell!(Registers::new());
         let stack = rc_refcell!(Stack::new(memory.clone()));
+        let state = rc_refcell!(CpuState::new());
         return Cpu {
             _counter: program.clone(),
             _registers: registers.clone(),
             _stack: stack.clone(),
+            _state: state.clone(),
             _context: InstructionContext::new(
-                memory.clone(), program.clone(), registers.clone(), stack.clone()),
+                memory.clone(), program.clone(), registers.clone(), stack.clone(), state.clone()),
+            _trace_sink: None,
         };
     }
     
-    pub fn run_next_instruction(&mut self) {
-        let instruction = self._counter.borrow_mut().read_next_instruction();
-        instruction.run(&mut self._context);
+    // Returns the number of T-cycles that passed
+    pub fn run_next_instruction(&mut self) -> Result<u16, DecodeError> {
+        if let Some(error) = self._state.borrow().get_lock() {
+            return Err(error);
+        }
+        
+        if self.is_sleeping() {
+            // The clock keeps ticking while the CPU waits for an interrupt
+            return Ok(T_CYCLES_PER_M_CYCLE);
+        }
+        
+        let enable_interrupts_after = self._state.borrow().is_interrupts_enable_scheduled();
+        let trace_record = self.start_trace_record();
+        let decoded = self._counter.borrow_mut().read_next_instruction();
+        let instruction = match decoded {
+            Ok(instruction) => instruction,
+            Err(error) => {
+                self._state.borrow_mut().lock(error);
+                return Err(error);
+            },
+        };
+        let m_cycles = instruction.run(&mut self._context);
+        
+        if enable_interrupts_after {
+            self._state.borrow_mut().apply_scheduled_interrupts_enable();
+        }
+        
+        let cycles = (m_cycles as u16) * T_CYCLES_PER_M_CYCLE;
+        self.finish_trace_record(trace_record, cycles);
+        return Ok(cycles);
+    }
+    
+    pub fn set_trace_sink(&mut self, sink: Rc<RefCell<dyn TraceSink>>) {
+        self._trace_sink = Some(sink);
+    }
+    
+    // Captures the state before the instruction runs, only while tracing
+    fn start_trace_record(&self) -> Option<TraceRecord> {
+        self._trace_sink.as_ref()?;
+        
+        let program_counter = self._counter.borrow().get_counter();
+        let memory = self._context.memory();
+        let mut bytes = [0; TRACE_BYTES];
+        for (offset, byte) in bytes.iter_mut().enumerate() {
+            *byte = memory.read_byte(program_counter.wrapping_add(offset as u16));
+        }
+        
+        let registers = self._registers.borrow();
+        return Some(TraceRecord {
+            program_counter: program_counter,
+            bytes: bytes,
+            length: 0,
+            a: registers.a.get(),
+            f: registers.f.get(),
+            b: registers.b.get(),
+            c: registers.c.get(),
+            d: registers.d.get(),
+            e: registers.e.get(),
+            h: registers.h.get(),
+            l: registers.l.get(),
+            stack_pointer: self._stack.borrow().get_pointer(),
+            cycles: 0,
+        });
+    }
+    
+    fn finish_trace_record(&self, trace_record: Option<TraceRecord>, cycles: u16) {
+        if let (Some(mut record), Some(sink)) = (trace_record, &self._trace_sink) {
+            record.length = self._counter.borrow().get_instruction_length() as usize;
+            record.cycles = cycles;
+            sink.borrow_mut().record(&record);
+        }
+    }
+    
+    pub fn reset_to_post_boot_state(&mut self) {
+        let mut registers = self._registers.borrow_mut();
+        registers.af.set(POST_BOOT_AF);
+        registers.bc.set(POST_BOOT_BC);
+        registers.de.set(POST_BOOT_DE);
+        registers.hl.set(POST_BOOT_HL);
+        self._stack.borrow_mut().set_pointer(POST_BOOT_STACK_POINTER);
+        self._counter.borrow_mut().set_counter(POST_BOOT_PROGRAM_COUNTER);
+    }
+    
+    fn is_sleeping(&mut self) -> bool {
+        let is_stopped = self._state.borrow().is_stopped();
+        let is_halted = self._state.borrow().is_halted();
+        if !is_stopped && !is_halted {
+            return false;
+        }
+        
+        // HALT wakes on any pending interrupt, STOP only on a joypad press
+        let wake_up_mask = if is_stopped { JOYPAD_INTERRUPT_MASK } else { INTERRUPT_MASK };
+        if self.get_pending_interrupts() & wake_up_mask == 0 {
+            return true;
+        }
+        
+        self._state.borrow_mut().resume();
+        return false;
+    }
+    
+    fn get_pending_interrupts(&self) -> u8 {
+        let memory = self._context.memory();
+        let requested = memory.read_byte(INTERRUPT_FLAG_ADDRESS);
+        let enabled = memory.read_byte(INTERRUPT_ENABLE_ADDRESS);
+        return requested & enabled & INTERRUPT_MASK;
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use super::super::trace::{RingBufferTraceSink, TraceFormat};
     use crate::{as_hex, rc_refcell};
     
     #[test]
@@ -55,8 +180,228 @@ mod tests {
         cpu._counter.borrow_mut().set_counter(COUNTER);
 		cpu._registers.borrow_mut().a.set(INITIAL_A);
         
-        cpu.run_next_instruction();
+        cpu.run_next_instruction().unwrap();
         
         assert_eq!(as_hex!(cpu._registers.borrow().a), as_hex!(EXPECTED_A));
     }
+    
+    #[test]
+    fn test_run_next_instruction_returns_t_cycles() {
+        const LOAD_FROM_CONSTANT_ADDRESS_INSTRUCTION: u8 = 0xFA;
+        const COUNTER: u16 = 0xC000;
+        const EXPECTED_CYCLES: u16 = 16;
+        
+        let mut memory = Memory::new();
+        memory.write_byte(COUNTER, LOAD_FROM_CONSTANT_ADDRESS_INSTRUCTION);
+        
+        let mut cpu = Cpu::new(rc_refcell!(memory));
+        cpu._counter.borrow_mut().set_counter(COUNTER);
+        
+        let cycles = cpu.run_next_instruction();
+        
+        assert_eq!(cycles, Ok(EXPECTED_CYCLES));
+    }
+    
+    #[test]
+    fn test_run_next_instruction_prefix_instruction_returns_t_cycles() {
+        const PREFIX: u8 = 0xCB;
+        const ROTATE_MEMORY_LEFT_INSTRUCTION: u8 = 0x06;
+        const COUNTER: u16 = 0xC000;
+        const EXPECTED_CYCLES: u16 = 16;
+        
+        let mut memory = Memory::new();
+        memory.write_byte(COUNTER, PREFIX);
+        memory.write_byte(COUNTER+1, ROTATE_MEMORY_LEFT_INSTRUCTION);
+        
+        let mut cpu = Cpu::new(rc_refcell!(memory));
+        cpu._counter.borrow_mut().set_counter(COUNTER);
+        
+        let cycles = cpu.run_next_instruction();
+        
+        assert_eq!(cycles, Ok(EXPECTED_CYCLES));
+    }
+    
+    #[test]
+    fn test_run_next_instruction_halted_still_returns_cycles() {
+        const HALT_INSTRUCTION: u8 = 0x76;
+        const COUNTER: u16 = 0xC000;
+        
+        let mut memory = Memory::new();
+        memory.write_byte(COUNTER, HALT_INSTRUCTION);
+        
+        let mut cpu = Cpu::new(rc_refcell!(memory));
+        cpu._counter.borrow_mut().set_counter(COUNTER);
+        cpu.run_next_instruction().unwrap();
+        
+        let cycles = cpu.run_next_instruction();
+        
+        assert_eq!(cycles, Ok(T_CYCLES_PER_M_CYCLE));
+    }
+    
+    #[test]
+    fn test_run_next_instruction_without_trace_sink_runs() {
+        const NOP_INSTRUCTION: u8 = 0x00;
+        const COUNTER: u16 = 0xC000;
+        
+        let mut memory = Memory::new();
+        memory.write_byte(COUNTER, NOP_INSTRUCTION);
+        
+        let mut cpu = Cpu::new(rc_refcell!(memory));
+        cpu._counter.borrow_mut().set_counter(COUNTER);
+        
+        let result = cpu.run_next_instruction();
+        
+        assert_eq!(result.is_ok(), true);
+    }
+    
+    #[test]
+    fn test_run_next_instruction_with_trace_sink_records_instruction() {
+        const LOAD_A_INSTRUCTION: u8 = 0x3E;
+        const VALUE: u8 = 0x42;
+        const COUNTER: u16 = 0xC000;
+        const STACK_POINTER: u16 = 0xDFFE;
+        const INITIAL_B: u8 = 0x12;
+        
+        let mut memory = Memory::new();
+        memory.write_byte(COUNTER, LOAD_A_INSTRUCTION);
+        memory.write_byte(COUNTER+1, VALUE);
+        
+        let mut cpu = Cpu::new(rc_refcell!(memory));
+        cpu._counter.borrow_mut().set_counter(COUNTER);
+        cpu._stack.borrow_mut().set_pointer(STACK_POINTER);
+        cpu._registers.borrow_mut().b.set(INITIAL_B);
+        let sink = rc_refcell!(RingBufferTraceSink::new(4));
+        cpu.set_trace_sink(sink.clone());
+        
+        cpu.run_next_instruction().unwrap();
+        
+        let records = sink.borrow().records();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].program_counter, COUNTER);
+        assert_eq!(records[0].opcode_bytes(), &[LOAD_A_INSTRUCTION, VALUE]);
+        assert_eq!(records[0].a, 0x00);
+        assert_eq!(records[0].b, INITIAL_B);
+        assert_eq!(records[0].stack_pointer, STACK_POINTER);
+        assert_eq!(records[0].cycles, 8);
+    }
+    
+    #[test]
+    fn test_reset_to_post_boot_state_traces_like_reference_logs() {
+        const NOP_INSTRUCTION: u8 = 0x00;
+        const JUMP_INSTRUCTION: u8 = 0xC3;
+        const EXPECTED_LINE: &str = "A:01 F:B0 B:00 C:13 D:00 E:D8 H:01 L:4D SP:FFFE PC:0100 PCMEM:00,C3,13,02";
+        
+        let mut rom = vec![0; 0x0104];
+        rom[0x0100] = NOP_INSTRUCTION;
+        rom[0x0101] = JUMP_INSTRUCTION;
+        rom[0x0102] = 0x13;
+        rom[0x0103] = 0x02;
+        let mut memory = Memory::new();
+        memory.load_rom(&rom);
+        
+        let mut cpu = Cpu::new(rc_refcell!(memory));
+        let sink = rc_refcell!(RingBufferTraceSink::new(1));
+        cpu.set_trace_sink(sink.clone());
+        cpu.reset_to_post_boot_state();
+        
+        cpu.run_next_instruction().unwrap();
+        
+        let records = sink.borrow().records();
+        assert_eq!(TraceFormat::GameboyDoctor.format(&records[0]), EXPECTED_LINE);
+    }
+    
+    #[test]
+    fn test_run_next_instruction_illegal_opcode_locks_cpu() {
+        const ILLEGAL_INSTRUCTION: u8 = 0xD3;
+        const COUNTER: u16 = 0xC000;
+        
+        let mut memory = Memory::new();
+        memory.write_byte(COUNTER, ILLEGAL_INSTRUCTION);
+        
+        let mut cpu = Cpu::new(rc_refcell!(memory));
+        cpu._counter.borrow_mut().set_counter(COUNTER);
+        
+        let result = cpu.run_next_instruction();
+        
+        assert_eq!(result, Err(DecodeError::IllegalOpcode {address: COUNTER, opcode: ILLEGAL_INSTRUCTION}));
+        assert_eq!(cpu._state.borrow().is_locked(), true);
+    }
+    
+    #[test]
+    fn test_run_next_instruction_locked_does_not_run_instruction() {
+        const ILLEGAL_INSTRUCTION: u8 = 0xD3;
+        const NOP_INSTRUCTION: u8 = 0x00;
+        const COUNTER: u16 = 0xC000;
+        
+        let mut memory = Memory::new();
+        memory.write_byte(COUNTER, ILLEGAL_INSTRUCTION);
+        memory.write_byte(COUNTER+1, NOP_INSTRUCTION);
+        
+        let mut cpu = Cpu::new(rc_refcell!(memory));
+        cpu._counter.borrow_mut().set_counter(COUNTER);
+        let first_result = cpu.run_next_instruction();
+        
+        let result = cpu.run_next_instruction();
+        
+        assert_eq!(result, first_result);
+        assert_eq!(cpu._counter.borrow().get_counter(), COUNTER+1);
+    }
+    
+    #[test]
+    fn test_run_next_instruction_halted_does_not_run_instruction() {
+        const HALT_INSTRUCTION: u8 = 0x76;
+        const COUNTER: u16 = 0xC000;
+        
+        let mut memory = Memory::new();
+        memory.write_byte(COUNTER, HALT_INSTRUCTION);
+        
+        let mut cpu = Cpu::new(rc_refcell!(memory));
+        cpu._counter.borrow_mut().set_counter(COUNTER);
+        
+        cpu.run_next_instruction().unwrap();
+        cpu.run_next_instruction().unwrap();
+        
+        assert_eq!(cpu._state.borrow().is_halted(), true);
+        assert_eq!(cpu._counter.borrow().get_counter(), COUNTER+1);
+    }
+    
+    #[test]
+    fn test_run_next_instruction_halted_wakes_on_pending_interrupt() {
+        const HALT_INSTRUCTION: u8 = 0x76;
+        const COUNTER: u16 = 0xC000;
+        
+        let memory = rc_refcell!(Memory::new());
+        memory.borrow_mut().write_byte(COUNTER, HALT_INSTRUCTION);
+        
+        let mut cpu = Cpu::new(memory.clone());
+        cpu._counter.borrow_mut().set_counter(COUNTER);
+        cpu.run_next_instruction().unwrap();
+        memory.borrow_mut().write_byte(INTERRUPT_ENABLE_ADDRESS, 0x04);
+        memory.borrow_mut().write_byte(INTERRUPT_FLAG_ADDRESS, 0x04);
+        
+        cpu.run_next_instruction().unwrap();
+        
+        assert_eq!(cpu._state.borrow().is_halted(), false);
+        assert_eq!(cpu._counter.borrow().get_counter(), COUNTER+2);
+    }
+    
+    #[test]
+    fn test_run_next_instruction_enable_interrupts_waits_one_instruction() {
+        const EI_INSTRUCTION: u8 = 0xFB;
+        const NOP_INSTRUCTION: u8 = 0x00;
+        const COUNTER: u16 = 0xC000;
+        
+        let mut memory = Memory::new();
+        memory.write_byte(COUNTER, EI_INSTRUCTION);
+        memory.write_byte(COUNTER+1, NOP_INSTRUCTION);
+        
+        let mut cpu = Cpu::new(rc_refcell!(memory));
+        cpu._counter.borrow_mut().set_counter(COUNTER);
+        
+        cpu.run_next_instruction().unwrap();
+        assert_eq!(cpu._state.borrow().are_interrupts_enabled(), false);
+        
+        cpu.run_next_instruction().unwrap();
+        assert_eq!(cpu._state.borrow().are_interrupts_enabled(), true);
+    }
 }
@@ -0,0 +1,142 @@
+use super::decode_error::DecodeError;
+
+pub struct CpuState {
+    _halted: bool,
+    _stopped: bool,
+    _interrupts_enabled: bool,
+    _interrupts_enable_scheduled: bool,
+    _lock: Option<DecodeError>,
+}
+
+impl CpuState {
+    pub fn new() -> CpuState {
+        return CpuState {
+            _halted: false,
+            _stopped: false,
+            _interrupts_enabled: false,
+            _interrupts_enable_scheduled: false,
+            _lock: None,
+        };
+    }
+    
+    pub fn is_halted(&self) -> bool {
+        return self._halted;
+    }
+    
+    pub fn halt(&mut self) {
+        self._halted = true;
+    }
+    
+    pub fn is_stopped(&self) -> bool {
+        return self._stopped;
+    }
+    
+    pub fn stop(&mut self) {
+        self._stopped = true;
+    }
+    
+    pub fn is_locked(&self) -> bool {
+        return self._lock.is_some();
+    }
+    
+    pub fn get_lock(&self) -> Option<DecodeError> {
+        return self._lock;
+    }
+    
+    // Only a reset gets the CPU out of a lock up, so there is no unlock
+    pub fn lock(&mut self, error: DecodeError) {
+        self._lock = Some(error);
+    }
+    
+    pub fn resume(&mut self) {
+        self._halted = false;
+        self._stopped = false;
+    }
+    
+    pub fn are_interrupts_enabled(&self) -> bool {
+        return self._interrupts_enabled;
+    }
+    
+    pub fn is_interrupts_enable_scheduled(&self) -> bool {
+        return self._interrupts_enable_scheduled;
+    }
+    
+    pub fn enable_interrupts(&mut self) {
+        self._interrupts_enabled = true;
+        self._interrupts_enable_scheduled = false;
+    }
+    
+    pub fn schedule_interrupts_enable(&mut self) {
+        self._interrupts_enable_scheduled = true;
+    }
+    
+    pub fn apply_scheduled_interrupts_enable(&mut self) {
+        if self._interrupts_enable_scheduled {
+            self.enable_interrupts();
+        }
+    }
+    
+    pub fn disable_interrupts(&mut self) {
+        self._interrupts_enabled = false;
+        self._interrupts_enable_scheduled = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_resume_clears_halted_and_stopped() {
+        let mut state = CpuState::new();
+        state.halt();
+        state.stop();
+        
+        state.resume();
+        
+        assert_eq!(state.is_halted(), false);
+        assert_eq!(state.is_stopped(), false);
+    }
+    
+    #[test]
+    fn test_lock_records_error() {
+        let error = DecodeError::IllegalOpcode {address: 0x1234, opcode: 0xED};
+        let mut state = CpuState::new();
+        
+        state.lock(error);
+        
+        assert_eq!(state.is_locked(), true);
+        assert_eq!(state.get_lock(), Some(error));
+    }
+    
+    #[test]
+    fn test_schedule_interrupts_enable_does_not_enable_interrupts() {
+        let mut state = CpuState::new();
+        
+        state.schedule_interrupts_enable();
+        
+        assert_eq!(state.are_interrupts_enabled(), false);
+    }
+    
+    #[test]
+    fn test_apply_scheduled_interrupts_enable_enables_interrupts() {
+        let mut state = CpuState::new();
+        state.schedule_interrupts_enable();
+        
+        state.apply_scheduled_interrupts_enable();
+        
+        assert_eq!(state.are_interrupts_enabled(), true);
+        assert_eq!(state.is_interrupts_enable_scheduled(), false);
+    }
+    
+    #[test]
+    fn test_disable_interrupts_cancels_scheduled_enable() {
+        let mut state = CpuState::new();
+        state.schedule_interrupts_enable();
+        
+        state.disable_interrupts();
+        state.apply_scheduled_interrupts_enable();
+        
+        assert_eq!(state.are_interrupts_enabled(), false);
+    }
+}
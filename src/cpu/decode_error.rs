@@ -0,0 +1,36 @@
+use crate::as_hex;
+
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DecodeError {
+    // One of the opcodes the SM83 leaves undefined, which hangs the real hardware
+    IllegalOpcode {address: u16, opcode: u8},
+    UnknownPrefixOpcode {address: u16, opcode: u8},
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        return match *self {
+            DecodeError::IllegalOpcode {address, opcode} =>
+                write!(formatter, "Illegal opcode {} at {}", as_hex!(opcode), as_hex!(address)),
+            DecodeError::UnknownPrefixOpcode {address, opcode} =>
+                write!(formatter, "Unknown prefix opcode {} at {}", as_hex!(opcode), as_hex!(address)),
+        };
+    }
+}
+
+impl Error for DecodeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_display_illegal_opcode_reports_opcode_and_address() {
+        let error = DecodeError::IllegalOpcode {address: 0x1234, opcode: 0xD3};
+        
+        assert_eq!(error.to_string(), "Illegal opcode 0xD3 at 0x1234");
+    }
+}
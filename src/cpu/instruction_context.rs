@@ -1,3 +1,4 @@
+use super::cpu_state::CpuState;
 use super::program_counter::ProgramCounter;
 use super::registers::registers::Registers;
 use super::stack::Stack;
@@ -11,6 +12,7 @@ pub struct InstructionContext {
     _program: Rc<RefCell<ProgramCounter>>,
     _registers: Rc<RefCell<Registers>>,
     _stack: Rc<RefCell<Stack>>,
+    _state: Rc<RefCell<CpuState>>,
 }
 
 impl InstructionContext {
@@ -18,12 +20,14 @@ impl InstructionContext {
             memory: Rc<RefCell<Memory>>,
             program: Rc<RefCell<ProgramCounter>>,
             registers: Rc<RefCell<Registers>>,
-            stack: Rc<RefCell<Stack>>) -> InstructionContext {
+            stack: Rc<RefCell<Stack>>,
+            state: Rc<RefCell<CpuState>>) -> InstructionContext {
 		return InstructionContext {
             _memory: memory,
             _program: program,
             _registers: registers,
-            _stack: stack};
+            _stack: stack,
+            _state: state};
 	}
     
     pub fn memory(&self) -> std::cell::Ref<Memory> {
@@ -57,4 +61,12 @@ impl InstructionContext {
     pub fn stack_mut(&self) -> std::cell::RefMut<Stack> {
         return self._stack.borrow_mut();
     }
+    
+    pub fn state(&self) -> std::cell::Ref<CpuState> {
+        return self._state.borrow();
+    }
+    
+    pub fn state_mut(&self) -> std::cell::RefMut<CpuState> {
+        return self._state.borrow_mut();
+    }
 }
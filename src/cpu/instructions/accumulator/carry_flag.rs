@@ -0,0 +1,78 @@
+use super::super::super::InstructionContext;
+
+pub fn set_carry_flag(context: &mut InstructionContext, value: u8) -> u8 {
+    context.registers_mut().subtract_flag.reset();
+    context.registers_mut().half_carry_flag.reset();
+    context.registers_mut().carry_flag.activate();
+    
+    return value;
+}
+
+pub fn complement_carry_flag(context: &mut InstructionContext, value: u8) -> u8 {
+    let carry = context.registers().carry_flag.get();
+    
+    context.registers_mut().subtract_flag.reset();
+    context.registers_mut().half_carry_flag.reset();
+    context.registers_mut().carry_flag.set(!carry);
+    
+    return value;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::as_hex;
+    use crate::cpu::testing::build_test_instruction_context;
+    
+    #[test]
+    fn test_set_carry_flag_activates_carry_flag() {
+        let mut context = build_test_instruction_context();
+        context.registers_mut().carry_flag.reset();
+        
+        set_carry_flag(&mut context, 0x12);
+        
+        assert_eq!(context.registers().carry_flag.get(), true);
+    }
+    
+    #[test]
+    fn test_set_carry_flag_resets_subtract_and_half_carry_flags() {
+        let mut context = build_test_instruction_context();
+        context.registers_mut().subtract_flag.activate();
+        context.registers_mut().half_carry_flag.activate();
+        
+        set_carry_flag(&mut context, 0x12);
+        
+        assert_eq!(context.registers().subtract_flag.get(), false);
+        assert_eq!(context.registers().half_carry_flag.get(), false);
+    }
+    
+    #[test]
+    fn test_set_carry_flag_returns_value_unchanged() {
+        const VALUE: u8 = 0x12;
+        let mut context = build_test_instruction_context();
+        
+        let result = set_carry_flag(&mut context, VALUE);
+        
+        assert_eq!(as_hex!(result), as_hex!(VALUE));
+    }
+    
+    #[test]
+    fn test_complement_carry_flag_flag_on_resets_carry_flag() {
+        let mut context = build_test_instruction_context();
+        context.registers_mut().carry_flag.activate();
+        
+        complement_carry_flag(&mut context, 0x12);
+        
+        assert_eq!(context.registers().carry_flag.get(), false);
+    }
+    
+    #[test]
+    fn test_complement_carry_flag_flag_off_activates_carry_flag() {
+        let mut context = build_test_instruction_context();
+        context.registers_mut().carry_flag.reset();
+        
+        complement_carry_flag(&mut context, 0x12);
+        
+        assert_eq!(context.registers().carry_flag.get(), true);
+    }
+}
@@ -0,0 +1,52 @@
+use super::super::super::InstructionContext;
+
+pub fn complement(context: &mut InstructionContext, value: u8) -> u8 {
+    let new_value = !value;
+    
+    context.registers_mut().subtract_flag.activate();
+    context.registers_mut().half_carry_flag.activate();
+    
+    return new_value;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::as_hex;
+    use crate::cpu::testing::build_test_instruction_context;
+    
+    #[test]
+    fn test_complement_inverts_value() {
+        const VALUE: u8 = 0x35;
+        const EXPECTED_VALUE: u8 = 0xCA;
+        let mut context = build_test_instruction_context();
+        
+        let result = complement(&mut context, VALUE);
+        
+        assert_eq!(as_hex!(result), as_hex!(EXPECTED_VALUE));
+    }
+    
+    #[test]
+    fn test_complement_activates_subtract_and_half_carry_flags() {
+        let mut context = build_test_instruction_context();
+        context.registers_mut().subtract_flag.reset();
+        context.registers_mut().half_carry_flag.reset();
+        
+        complement(&mut context, 0x00);
+        
+        assert_eq!(context.registers().subtract_flag.get(), true);
+        assert_eq!(context.registers().half_carry_flag.get(), true);
+    }
+    
+    #[test]
+    fn test_complement_leaves_zero_and_carry_flags_alone() {
+        let mut context = build_test_instruction_context();
+        context.registers_mut().zero_flag.reset();
+        context.registers_mut().carry_flag.activate();
+        
+        complement(&mut context, 0xFF);
+        
+        assert_eq!(context.registers().zero_flag.get(), false);
+        assert_eq!(context.registers().carry_flag.get(), true);
+    }
+}
@@ -0,0 +1,121 @@
+use super::super::super::InstructionContext;
+
+pub fn decimal_adjust(context: &mut InstructionContext, value: u8) -> u8 {
+    let subtract = context.registers().subtract_flag.get();
+    let half_carry = context.registers().half_carry_flag.get();
+    let mut carry = context.registers().carry_flag.get();
+    let mut new_value = value;
+    
+    if subtract {
+        if carry {
+            new_value = new_value.wrapping_sub(0x60);
+        }
+        if half_carry {
+            new_value = new_value.wrapping_sub(0x06);
+        }
+    } else {
+        if carry || value > 0x99 {
+            new_value = new_value.wrapping_add(0x60);
+            carry = true;
+        }
+        if half_carry || (value & 0x0F) > 0x09 {
+            new_value = new_value.wrapping_add(0x06);
+        }
+    }
+    
+    context.registers_mut().zero_flag.set(new_value == 0);
+    context.registers_mut().half_carry_flag.reset();
+    context.registers_mut().carry_flag.set(carry);
+    
+    return new_value;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::as_hex;
+    use crate::cpu::testing::build_test_instruction_context;
+    
+    fn build_context(subtract: bool, half_carry: bool, carry: bool) -> InstructionContext {
+        let mut context = build_test_instruction_context();
+        context.registers_mut().subtract_flag.set(subtract);
+        context.registers_mut().half_carry_flag.set(half_carry);
+        context.registers_mut().carry_flag.set(carry);
+        return context;
+    }
+    
+    #[test]
+    fn test_decimal_adjust_after_add_valid_bcd_is_unchanged() {
+        let mut context = build_context(false, false, false);
+        
+        let result = decimal_adjust(&mut context, 0x45);
+        
+        assert_eq!(as_hex!(result), as_hex!(0x45));
+        assert_eq!(context.registers().carry_flag.get(), false);
+    }
+    
+    #[test]
+    fn test_decimal_adjust_after_add_lower_digit_overflowed_adjusts_lower_digit() {
+        // 0x19 + 0x28 = 0x41 with half carry, should be 47
+        let mut context = build_context(false, true, false);
+        
+        let result = decimal_adjust(&mut context, 0x41);
+        
+        assert_eq!(as_hex!(result), as_hex!(0x47));
+    }
+    
+    #[test]
+    fn test_decimal_adjust_after_add_upper_digit_overflowed_adjusts_and_sets_carry() {
+        // 0x90 + 0x20 = 0xB0, should be 110
+        let mut context = build_context(false, false, false);
+        
+        let result = decimal_adjust(&mut context, 0xB0);
+        
+        assert_eq!(as_hex!(result), as_hex!(0x10));
+        assert_eq!(context.registers().carry_flag.get(), true);
+    }
+    
+    #[test]
+    fn test_decimal_adjust_after_add_rolls_to_zero_sets_zero_flag() {
+        // 0x99 + 0x01 = 0x9A, should be 100
+        let mut context = build_context(false, false, false);
+        
+        let result = decimal_adjust(&mut context, 0x9A);
+        
+        assert_eq!(as_hex!(result), as_hex!(0x00));
+        assert_eq!(context.registers().zero_flag.get(), true);
+        assert_eq!(context.registers().carry_flag.get(), true);
+    }
+    
+    #[test]
+    fn test_decimal_adjust_after_subtract_half_borrow_adjusts_lower_digit() {
+        // 0x42 - 0x05 = 0x3D with half borrow, should be 37
+        let mut context = build_context(true, true, false);
+        
+        let result = decimal_adjust(&mut context, 0x3D);
+        
+        assert_eq!(as_hex!(result), as_hex!(0x37));
+        assert_eq!(context.registers().carry_flag.get(), false);
+    }
+    
+    #[test]
+    fn test_decimal_adjust_after_subtract_borrow_adjusts_upper_digit_and_keeps_carry() {
+        // 0x10 - 0x20 = 0xF0 with borrow, should be 90
+        let mut context = build_context(true, false, true);
+        
+        let result = decimal_adjust(&mut context, 0xF0);
+        
+        assert_eq!(as_hex!(result), as_hex!(0x90));
+        assert_eq!(context.registers().carry_flag.get(), true);
+    }
+    
+    #[test]
+    fn test_decimal_adjust_resets_half_carry_flag_and_keeps_subtract_flag() {
+        let mut context = build_context(true, true, false);
+        
+        decimal_adjust(&mut context, 0x3D);
+        
+        assert_eq!(context.registers().half_carry_flag.get(), false);
+        assert_eq!(context.registers().subtract_flag.get(), true);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod instructions;
+
+mod carry_flag;
+mod complement;
+mod decimal_adjust;
@@ -0,0 +1,187 @@
+use super::super::utils::{check_half_carry, check_half_carry_short};
+use super::super::super::instruction_context::InstructionContext;
+use crate::get_lower_u8;
+
+pub fn add_short_to_hl(context: &mut InstructionContext, value: u16) -> u16 {
+    let hl_value = context.registers().hl.get();
+    let (new_value, overflow) = hl_value.overflowing_add(value);
+    
+    context.registers_mut().subtract_flag.reset();
+    context.registers_mut().carry_flag.set(overflow);
+    context.registers_mut().half_carry_flag.set(check_half_carry_short(hl_value, value));
+    
+    return new_value;
+}
+
+pub fn add_signed_constant_byte(context: &mut InstructionContext, value: u16) -> u16 {
+    let offset = context.program_mut().read_next_byte();
+    let new_value = value.wrapping_add(offset as i8 as u16);
+    let lower_value = get_lower_u8!(value);
+    
+    context.registers_mut().zero_flag.reset();
+    context.registers_mut().subtract_flag.reset();
+    context.registers_mut().carry_flag.set(lower_value.overflowing_add(offset).1);
+    context.registers_mut().half_carry_flag.set(check_half_carry(lower_value, offset));
+    
+    return new_value;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::as_hex;
+    use crate::cpu::testing::build_test_instruction_context;
+    
+    const COUNTER: u16 = 0xC010;
+    
+    #[test]
+    fn test_add_short_to_hl_returns_added_value() {
+        const HL_VALUE: u16 = 0x1234;
+        const VALUE: u16 = 0x0101;
+        const EXPECTED_VALUE: u16 = HL_VALUE + VALUE;
+        let mut context = build_test_instruction_context();
+        context.registers_mut().hl.set(HL_VALUE);
+        
+        let result = add_short_to_hl(&mut context, VALUE);
+        
+        assert_eq!(as_hex!(result), as_hex!(EXPECTED_VALUE));
+    }
+    
+    #[test]
+    fn test_add_short_to_hl_leaves_zero_flag_alone() {
+        const HL_VALUE: u16 = 0xFFFF;
+        const VALUE: u16 = 0x0001;
+        let mut context = build_test_instruction_context();
+        context.registers_mut().hl.set(HL_VALUE);
+        context.registers_mut().zero_flag.reset();
+        
+        add_short_to_hl(&mut context, VALUE);
+        
+        assert_eq!(context.registers().zero_flag.get(), false);
+    }
+    
+    #[test]
+    fn test_add_short_to_hl_turns_subtract_flag_off() {
+        let mut context = build_test_instruction_context();
+        context.registers_mut().subtract_flag.activate();
+        
+        add_short_to_hl(&mut context, 0x1234);
+        
+        assert_eq!(context.registers().subtract_flag.get(), false);
+    }
+    
+    #[test]
+    fn test_add_short_to_hl_overflowed_sets_carry_flag_on() {
+        const HL_VALUE: u16 = 0xF000;
+        const VALUE: u16 = 0x1000;
+        let mut context = build_test_instruction_context();
+        context.registers_mut().hl.set(HL_VALUE);
+        context.registers_mut().carry_flag.reset();
+        
+        add_short_to_hl(&mut context, VALUE);
+        
+        assert_eq!(context.registers().carry_flag.get(), true);
+    }
+    
+    #[test]
+    fn test_add_short_to_hl_bit_eleven_overflowed_sets_half_carry_flag_on() {
+        const HL_VALUE: u16 = 0x0FFF;
+        const VALUE: u16 = 0x0001;
+        let mut context = build_test_instruction_context();
+        context.registers_mut().hl.set(HL_VALUE);
+        context.registers_mut().half_carry_flag.reset();
+        
+        add_short_to_hl(&mut context, VALUE);
+        
+        assert_eq!(context.registers().half_carry_flag.get(), true);
+    }
+    
+    #[test]
+    fn test_add_short_to_hl_lower_byte_overflowed_sets_half_carry_flag_off() {
+        const HL_VALUE: u16 = 0x00FF;
+        const VALUE: u16 = 0x0001;
+        let mut context = build_test_instruction_context();
+        context.registers_mut().hl.set(HL_VALUE);
+        context.registers_mut().half_carry_flag.activate();
+        
+        add_short_to_hl(&mut context, VALUE);
+        
+        assert_eq!(context.registers().half_carry_flag.get(), false);
+    }
+    
+    #[test]
+    fn test_add_signed_constant_byte_positive_offset_returns_added_value() {
+        const VALUE: u16 = 0xFFF0;
+        const OFFSET: u8 = 0x05;
+        const EXPECTED_VALUE: u16 = 0xFFF5;
+        let mut context = build_test_instruction_context();
+        context.program_mut().set_counter(COUNTER);
+        context.memory_mut().write_byte(COUNTER, OFFSET);
+        
+        let result = add_signed_constant_byte(&mut context, VALUE);
+        
+        assert_eq!(as_hex!(result), as_hex!(EXPECTED_VALUE));
+    }
+    
+    #[test]
+    fn test_add_signed_constant_byte_negative_offset_returns_subtracted_value() {
+        const VALUE: u16 = 0xFFF0;
+        const OFFSET: u8 = 0xFE;
+        const EXPECTED_VALUE: u16 = 0xFFEE;
+        let mut context = build_test_instruction_context();
+        context.program_mut().set_counter(COUNTER);
+        context.memory_mut().write_byte(COUNTER, OFFSET);
+        
+        let result = add_signed_constant_byte(&mut context, VALUE);
+        
+        assert_eq!(as_hex!(result), as_hex!(EXPECTED_VALUE));
+    }
+    
+    #[test]
+    fn test_add_signed_constant_byte_turns_zero_and_subtract_flags_off() {
+        const VALUE: u16 = 0x0000;
+        const OFFSET: u8 = 0x00;
+        let mut context = build_test_instruction_context();
+        context.program_mut().set_counter(COUNTER);
+        context.memory_mut().write_byte(COUNTER, OFFSET);
+        context.registers_mut().zero_flag.activate();
+        context.registers_mut().subtract_flag.activate();
+        
+        add_signed_constant_byte(&mut context, VALUE);
+        
+        assert_eq!(context.registers().zero_flag.get(), false);
+        assert_eq!(context.registers().subtract_flag.get(), false);
+    }
+    
+    #[test]
+    fn test_add_signed_constant_byte_lower_byte_overflowed_sets_carry_and_half_carry_flags_on() {
+        const VALUE: u16 = 0x00FF;
+        const OFFSET: u8 = 0xFF;
+        let mut context = build_test_instruction_context();
+        context.program_mut().set_counter(COUNTER);
+        context.memory_mut().write_byte(COUNTER, OFFSET);
+        context.registers_mut().carry_flag.reset();
+        context.registers_mut().half_carry_flag.reset();
+        
+        add_signed_constant_byte(&mut context, VALUE);
+        
+        assert_eq!(context.registers().carry_flag.get(), true);
+        assert_eq!(context.registers().half_carry_flag.get(), true);
+    }
+    
+    #[test]
+    fn test_add_signed_constant_byte_no_lower_byte_overflow_sets_carry_and_half_carry_flags_off() {
+        const VALUE: u16 = 0xFF00;
+        const OFFSET: u8 = 0x01;
+        let mut context = build_test_instruction_context();
+        context.program_mut().set_counter(COUNTER);
+        context.memory_mut().write_byte(COUNTER, OFFSET);
+        context.registers_mut().carry_flag.activate();
+        context.registers_mut().half_carry_flag.activate();
+        
+        add_signed_constant_byte(&mut context, VALUE);
+        
+        assert_eq!(context.registers().carry_flag.get(), false);
+        assert_eq!(context.registers().half_carry_flag.get(), false);
+    }
+}
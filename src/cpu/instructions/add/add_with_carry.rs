@@ -0,0 +1,109 @@
+use super::super::utils::{check_half_carry_with_carry};
+use super::super::super::instruction_context::InstructionContext;
+
+pub fn add_with_carry(context: &mut InstructionContext, left_value: u8, right_value: u8) -> u8 {
+    let carry = context.registers().carry_flag.get();
+    let full_value = (left_value as u16) + (right_value as u16) + (carry as u16);
+    let new_value = full_value as u8;
+    
+    context.registers_mut().zero_flag.set(new_value == 0);
+    context.registers_mut().subtract_flag.reset();
+    context.registers_mut().carry_flag.set(full_value > 0xFF);
+    context.registers_mut().half_carry_flag.set(check_half_carry_with_carry(left_value, right_value, carry));
+    
+    return new_value;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::as_hex;
+    use crate::cpu::testing::build_test_instruction_context;
+    
+    #[test]
+    fn test_add_with_carry_carry_flag_off_returns_added_value() {
+        const LEFT_VALUE: u8 = 0x12;
+        const RIGHT_VALUE: u8 = 0x34;
+        const EXPECTED_VALUE: u8 = LEFT_VALUE + RIGHT_VALUE;
+        let mut context = build_test_instruction_context();
+        context.registers_mut().carry_flag.reset();
+        
+        let result = add_with_carry(&mut context, LEFT_VALUE, RIGHT_VALUE);
+        
+        assert_eq!(as_hex!(result), as_hex!(EXPECTED_VALUE));
+    }
+    
+    #[test]
+    fn test_add_with_carry_carry_flag_on_returns_added_value_plus_one() {
+        const LEFT_VALUE: u8 = 0x12;
+        const RIGHT_VALUE: u8 = 0x34;
+        const EXPECTED_VALUE: u8 = LEFT_VALUE + RIGHT_VALUE + 1;
+        let mut context = build_test_instruction_context();
+        context.registers_mut().carry_flag.activate();
+        
+        let result = add_with_carry(&mut context, LEFT_VALUE, RIGHT_VALUE);
+        
+        assert_eq!(as_hex!(result), as_hex!(EXPECTED_VALUE));
+    }
+    
+    #[test]
+    fn test_add_with_carry_becomes_zero_sets_zero_flag_to_true() {
+        const LEFT_VALUE: u8 = 0xFF;
+        const RIGHT_VALUE: u8 = 0x00;
+        let mut context = build_test_instruction_context();
+        context.registers_mut().carry_flag.activate();
+        
+        add_with_carry(&mut context, LEFT_VALUE, RIGHT_VALUE);
+        
+        assert_eq!(context.registers().zero_flag.get(), true);
+    }
+    
+    #[test]
+    fn test_add_with_carry_turns_subtract_flag_off() {
+        const LEFT_VALUE: u8 = 0x12;
+        const RIGHT_VALUE: u8 = 0x34;
+        let mut context = build_test_instruction_context();
+        context.registers_mut().subtract_flag.activate();
+        
+        add_with_carry(&mut context, LEFT_VALUE, RIGHT_VALUE);
+        
+        assert_eq!(context.registers().subtract_flag.get(), false);
+    }
+    
+    #[test]
+    fn test_add_with_carry_carry_pushes_past_overflow_sets_carry_flag_on() {
+        const LEFT_VALUE: u8 = 0xFF;
+        const RIGHT_VALUE: u8 = 0x00;
+        let mut context = build_test_instruction_context();
+        context.registers_mut().carry_flag.activate();
+        
+        add_with_carry(&mut context, LEFT_VALUE, RIGHT_VALUE);
+        
+        assert_eq!(context.registers().carry_flag.get(), true);
+    }
+    
+    #[test]
+    fn test_add_with_carry_no_overflow_sets_carry_flag_off() {
+        const LEFT_VALUE: u8 = 0xFE;
+        const RIGHT_VALUE: u8 = 0x00;
+        let mut context = build_test_instruction_context();
+        context.registers_mut().carry_flag.activate();
+        
+        add_with_carry(&mut context, LEFT_VALUE, RIGHT_VALUE);
+        
+        assert_eq!(context.registers().carry_flag.get(), false);
+    }
+    
+    #[test]
+    fn test_add_with_carry_carry_pushes_past_lower_nibble_sets_half_carry_flag_on() {
+        const LEFT_VALUE: u8 = 0x0F;
+        const RIGHT_VALUE: u8 = 0x00;
+        let mut context = build_test_instruction_context();
+        context.registers_mut().carry_flag.activate();
+        context.registers_mut().half_carry_flag.reset();
+        
+        add_with_carry(&mut context, LEFT_VALUE, RIGHT_VALUE);
+        
+        assert_eq!(context.registers().half_carry_flag.get(), true);
+    }
+}
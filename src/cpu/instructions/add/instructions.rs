@@ -1,29 +1,77 @@
 use super::add::{add};
-use super::super::common::binary_byte_op::BinaryByteOp;
+use super::add_short::{add_short_to_hl, add_signed_constant_byte};
+use super::add_with_carry::add_with_carry;
+use super::super::common::{BinaryByteOp, BinaryByteOpFn, UnaryShortOp};
+use super::super::destinations::{DoubleRegisterDestination, ShortDestination, StackPointerDestination};
 use super::super::instruction::Instruction;
-use super::super::sources::constant_byte_source::ConstantByteSource;
-use super::super::sources::register_source::RegisterSource;
-use super::super::super::registers::register_names::RegisterName;
+use super::super::sources::{AddressedByShortSource, ConstantByteSource, DoubleRegisterSource, RegisterSource, ShortSource, StackPointerSource};
+use super::super::super::registers::{DoubleRegisterName, RegisterName};
 use crate::{boxed, optional_boxed};
 
-fn build_add_instruction(other_source_name: RegisterName) -> Option<Box<dyn Instruction>> {
-    return optional_boxed!(BinaryByteOp::new_inplace_a_op(boxed!(RegisterSource::new(other_source_name)), add));
+fn build_add_instruction(other_source_name: RegisterName, operation: BinaryByteOpFn) -> Option<Box<dyn Instruction>> {
+    return optional_boxed!(BinaryByteOp::new_inplace_a_op(boxed!(RegisterSource::new(other_source_name)), operation, 1));
 }
 
-fn build_add_instruction_from_constant_byte() -> Option<Box<dyn Instruction>> {
-    return optional_boxed!(BinaryByteOp::new_inplace_a_op(boxed!(ConstantByteSource::new()), add));
+fn build_add_instruction_from_memory(operation: BinaryByteOpFn) -> Option<Box<dyn Instruction>> {
+    return optional_boxed!(BinaryByteOp::new_inplace_a_op(boxed!(AddressedByShortSource::new_from_register(DoubleRegisterName::HL)), operation, 2));
+}
+
+fn build_add_instruction_from_constant_byte(operation: BinaryByteOpFn) -> Option<Box<dyn Instruction>> {
+    return optional_boxed!(BinaryByteOp::new_inplace_a_op(boxed!(ConstantByteSource::new()), operation, 2));
+}
+
+fn build_add_short_to_hl_instruction(source: Box<dyn ShortSource>) -> Option<Box<dyn Instruction>> {
+    return optional_boxed!(
+        UnaryShortOp::new(
+            source,
+            add_short_to_hl,
+            boxed!(DoubleRegisterDestination::new(DoubleRegisterName::HL)),
+            2
+        )
+    );
+}
+
+fn build_add_to_stack_pointer_instruction(destination: Box<dyn ShortDestination>, cycles: u8) -> Option<Box<dyn Instruction>> {
+    return optional_boxed!(
+        UnaryShortOp::new(
+            boxed!(StackPointerSource::new()),
+            add_signed_constant_byte,
+            destination,
+            cycles
+        )
+    );
 }
 
 pub fn load_instruction(instruction_byte: u8) -> Option<Box<dyn Instruction>> {
     return match instruction_byte {
-        0x80 => build_add_instruction(RegisterName::B),
-        0x81 => build_add_instruction(RegisterName::C),
-        0x82 => build_add_instruction(RegisterName::D),
-        0x83 => build_add_instruction(RegisterName::E),
-        0x84 => build_add_instruction(RegisterName::H),
-        0x85 => build_add_instruction(RegisterName::L),
-        0x87 => build_add_instruction(RegisterName::A),
-        0xC6 => build_add_instruction_from_constant_byte(),
+        // Add
+        0x80 => build_add_instruction(RegisterName::B, add),
+        0x81 => build_add_instruction(RegisterName::C, add),
+        0x82 => build_add_instruction(RegisterName::D, add),
+        0x83 => build_add_instruction(RegisterName::E, add),
+        0x84 => build_add_instruction(RegisterName::H, add),
+        0x85 => build_add_instruction(RegisterName::L, add),
+        0x86 => build_add_instruction_from_memory(add),
+        0x87 => build_add_instruction(RegisterName::A, add),
+        0xC6 => build_add_instruction_from_constant_byte(add),
+        // Add with Carry
+        0x88 => build_add_instruction(RegisterName::B, add_with_carry),
+        0x89 => build_add_instruction(RegisterName::C, add_with_carry),
+        0x8A => build_add_instruction(RegisterName::D, add_with_carry),
+        0x8B => build_add_instruction(RegisterName::E, add_with_carry),
+        0x8C => build_add_instruction(RegisterName::H, add_with_carry),
+        0x8D => build_add_instruction(RegisterName::L, add_with_carry),
+        0x8E => build_add_instruction_from_memory(add_with_carry),
+        0x8F => build_add_instruction(RegisterName::A, add_with_carry),
+        0xCE => build_add_instruction_from_constant_byte(add_with_carry),
+        // Add Short to HL
+        0x09 => build_add_short_to_hl_instruction(boxed!(DoubleRegisterSource::new(DoubleRegisterName::BC))),
+        0x19 => build_add_short_to_hl_instruction(boxed!(DoubleRegisterSource::new(DoubleRegisterName::DE))),
+        0x29 => build_add_short_to_hl_instruction(boxed!(DoubleRegisterSource::new(DoubleRegisterName::HL))),
+        0x39 => build_add_short_to_hl_instruction(boxed!(StackPointerSource::new())),
+        // Add Signed Byte to Stack Pointer
+        0xE8 => build_add_to_stack_pointer_instruction(boxed!(StackPointerDestination::new()), 4),
+        0xF8 => build_add_to_stack_pointer_instruction(boxed!(DoubleRegisterDestination::new(DoubleRegisterName::HL)), 3),
         _ => None,
     };
 }
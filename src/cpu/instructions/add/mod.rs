@@ -1,3 +1,5 @@
 pub mod instructions;
 
 mod add;
+mod add_with_carry;
+mod add_short;
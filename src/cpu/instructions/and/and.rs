@@ -0,0 +1,97 @@
+use super::super::super::instruction_context::InstructionContext;
+
+pub fn and(context: &mut InstructionContext, left_value: u8, right_value: u8) -> u8 {
+    let new_value = left_value & right_value;
+    
+    context.registers_mut().zero_flag.set(new_value == 0);
+    context.registers_mut().subtract_flag.reset();
+    context.registers_mut().carry_flag.reset();
+    context.registers_mut().half_carry_flag.activate();
+    
+    return new_value;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::as_hex;
+    use crate::cpu::testing::build_test_instruction_context;
+    
+    #[test]
+    fn test_and_ands_values() {
+        const LEFT_VALUE: u8 = 0x3C;
+        const RIGHT_VALUE: u8 = 0x1F;
+        const EXPECTED_VALUE: u8 = LEFT_VALUE & RIGHT_VALUE;
+        
+        let mut context = build_test_instruction_context();
+        
+        let result = and(&mut context, LEFT_VALUE, RIGHT_VALUE);
+        
+        assert_eq!(as_hex!(result), as_hex!(EXPECTED_VALUE));
+    }
+    
+    #[test]
+    fn test_and_becomes_zero_sets_zero_flag_to_true() {
+        const LEFT_VALUE: u8 = 0xF0;
+        const RIGHT_VALUE: u8 = 0x0F;
+        
+        let mut context = build_test_instruction_context();
+        context.registers_mut().zero_flag.reset();
+        
+        and(&mut context, LEFT_VALUE, RIGHT_VALUE);
+        
+        assert_eq!(context.registers().zero_flag.get(), true);
+    }
+    
+    #[test]
+    fn test_and_becomes_non_zero_sets_zero_flag_to_false() {
+        const LEFT_VALUE: u8 = 0x12;
+        const RIGHT_VALUE: u8 = 0xFF;
+        
+        let mut context = build_test_instruction_context();
+        context.registers_mut().zero_flag.activate();
+        
+        and(&mut context, LEFT_VALUE, RIGHT_VALUE);
+        
+        assert_eq!(context.registers().zero_flag.get(), false);
+    }
+    
+    #[test]
+    fn test_and_turns_subtract_flag_off() {
+        const LEFT_VALUE: u8 = 0x34;
+        const RIGHT_VALUE: u8 = 0x12;
+        
+        let mut context = build_test_instruction_context();
+        context.registers_mut().subtract_flag.activate();
+        
+        and(&mut context, LEFT_VALUE, RIGHT_VALUE);
+        
+        assert_eq!(context.registers().subtract_flag.get(), false);
+    }
+    
+    #[test]
+    fn test_and_turns_carry_flag_off() {
+        const LEFT_VALUE: u8 = 0xFF;
+        const RIGHT_VALUE: u8 = 0xFF;
+        
+        let mut context = build_test_instruction_context();
+        context.registers_mut().carry_flag.activate();
+        
+        and(&mut context, LEFT_VALUE, RIGHT_VALUE);
+        
+        assert_eq!(context.registers().carry_flag.get(), false);
+    }
+    
+    #[test]
+    fn test_and_turns_half_carry_flag_on() {
+        const LEFT_VALUE: u8 = 0x20;
+        const RIGHT_VALUE: u8 = 0x10;
+        
+        let mut context = build_test_instruction_context();
+        context.registers_mut().half_carry_flag.reset();
+        
+        and(&mut context, LEFT_VALUE, RIGHT_VALUE);
+        
+        assert_eq!(context.registers().half_carry_flag.get(), true);
+    }
+}
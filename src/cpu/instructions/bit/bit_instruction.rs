@@ -7,15 +7,18 @@ use crate::{boxed};
 pub struct BitInstruction {
     source: Box<dyn ByteSource>,
     bit_number: u8,
+    cycles: u8,
 }
 
 impl BitInstruction {
 	pub fn new(
             source: Box<dyn ByteSource>,
-            bit_number: u8) -> BitInstruction {
+            bit_number: u8,
+            cycles: u8) -> BitInstruction {
 		return BitInstruction {
             source: source,
             bit_number: bit_number,
+            cycles: cycles,
         };
 	}
     
@@ -26,12 +29,13 @@ impl BitInstruction {
 		return BitInstruction {
             source: source,
             bit_number: bit_number,
+            cycles: 2,
         };
 	}
 }
 
 impl Instruction for BitInstruction {
-	fn run(&self, context: &mut InstructionContext) {
+	fn run(&self, context: &mut InstructionContext) -> u8 {
         let mask = 0x1 << self.bit_number;
         let value = self.source.read(context);
         let bit_is_off = value & mask == 0;
@@ -39,6 +43,7 @@ impl Instruction for BitInstruction {
         context.registers_mut().zero_flag.set(bit_is_off);
         context.registers_mut().subtract_flag.reset();
         context.registers_mut().half_carry_flag.activate();
+        return self.cycles;
 	}
 }
 
@@ -0,0 +1,121 @@
+use super::super::instruction::Instruction;
+use super::super::destinations::{AddressedByShortDestination, ByteDestination, RegisterDestination};
+use super::super::sources::{AddressedByShortSource, ByteSource, RegisterSource};
+use super::super::super::InstructionContext;
+use super::super::super::registers::{DoubleRegisterName, RegisterName};
+use crate::{boxed};
+
+pub struct ChangeBitInstruction {
+    source: Box<dyn ByteSource>,
+    destination: Box<dyn ByteDestination>,
+    bit_number: u8,
+    turn_on: bool,
+    cycles: u8,
+}
+
+impl ChangeBitInstruction {
+	pub fn new(
+            source: Box<dyn ByteSource>,
+            destination: Box<dyn ByteDestination>,
+            bit_number: u8,
+            turn_on: bool,
+            cycles: u8) -> ChangeBitInstruction {
+		return ChangeBitInstruction {
+            source: source,
+            destination: destination,
+            bit_number: bit_number,
+            turn_on: turn_on,
+            cycles: cycles,
+        };
+	}
+    
+	pub fn new_for_register(
+            register_name: RegisterName,
+            bit_number: u8,
+            turn_on: bool) -> ChangeBitInstruction {
+		return ChangeBitInstruction::new(
+            boxed!(RegisterSource::new(register_name)),
+            boxed!(RegisterDestination::new(register_name)),
+            bit_number,
+            turn_on,
+            2);
+	}
+    
+	pub fn new_for_memory(
+            register_name: DoubleRegisterName,
+            bit_number: u8,
+            turn_on: bool) -> ChangeBitInstruction {
+		return ChangeBitInstruction::new(
+            boxed!(AddressedByShortSource::new_from_register(register_name)),
+            boxed!(AddressedByShortDestination::new_from_register(register_name)),
+            bit_number,
+            turn_on,
+            4);
+	}
+}
+
+impl Instruction for ChangeBitInstruction {
+	fn run(&self, context: &mut InstructionContext) -> u8 {
+        let mask = 0x1 << self.bit_number;
+        let value = self.source.read(context);
+        let new_value = if self.turn_on { value | mask } else { value & !mask };
+        self.destination.assign(context, new_value);
+        return self.cycles;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpu::testing::build_test_instruction_context;
+    use crate::{as_hex};
+    
+    #[test]
+    fn test_run_turn_on_sets_bit() {
+        let mut context = build_test_instruction_context();
+        context.registers_mut().a.set(0x00);
+        
+        let instruction = ChangeBitInstruction::new_for_register(RegisterName::A, 4, true);
+        instruction.run(&mut context);
+        
+        assert_eq!(as_hex!(context.registers().a), as_hex!(0x10));
+    }
+    
+    #[test]
+    fn test_run_turn_off_resets_bit() {
+        let mut context = build_test_instruction_context();
+        context.registers_mut().a.set(0xFF);
+        
+        let instruction = ChangeBitInstruction::new_for_register(RegisterName::A, 4, false);
+        instruction.run(&mut context);
+        
+        assert_eq!(as_hex!(context.registers().a), as_hex!(0xEF));
+    }
+    
+    #[test]
+    fn test_run_for_memory_writes_back_to_memory() {
+        const ADDRESS: u16 = 0xC123;
+        let mut context = build_test_instruction_context();
+        context.registers_mut().hl.set(ADDRESS);
+        context.memory_mut().write_byte(ADDRESS, 0x01);
+        
+        let instruction = ChangeBitInstruction::new_for_memory(DoubleRegisterName::HL, 7, true);
+        instruction.run(&mut context);
+        
+        assert_eq!(as_hex!(context.memory().read_byte(ADDRESS)), as_hex!(0x81));
+    }
+    
+    #[test]
+    fn test_run_does_not_change_flags() {
+        let mut context = build_test_instruction_context();
+        context.registers_mut().a.set(0x00);
+        context.registers_mut().zero_flag.activate();
+        context.registers_mut().carry_flag.activate();
+        
+        let instruction = ChangeBitInstruction::new_for_register(RegisterName::A, 0, true);
+        instruction.run(&mut context);
+        
+        assert_eq!(context.registers().zero_flag.get(), true);
+        assert_eq!(context.registers().carry_flag.get(), true);
+    }
+}
@@ -1,21 +1,31 @@
 use super::bit_instruction::BitInstruction;
+use super::change_bit_instruction::ChangeBitInstruction;
 use super::super::instruction::Instruction;
-use super::super::super::registers::register_names::RegisterName;
-use super::super::sources::{ByteSource, };
+use super::super::super::registers::{DoubleRegisterName, RegisterName};
+use super::super::sources::{AddressedByShortSource};
 use crate::{boxed, optional_boxed};
 
 
 fn build_register_bit_instruction(register_name: RegisterName, bit_number: u8) -> Option<Box<dyn Instruction>> {
-    return optional_boxed!(BitInstruction::new_for_register(register_name, 0));
+    return optional_boxed!(BitInstruction::new_for_register(register_name, bit_number));
 }
 
-fn build_bit_instruction(source: Box<dyn ByteSource>, bit_number: u8) -> Option<Box<dyn Instruction>> {
-    return optional_boxed!(BitInstruction::new(source, 0));
+fn build_memory_bit_instruction(register_name: DoubleRegisterName, bit_number: u8) -> Option<Box<dyn Instruction>> {
+    return optional_boxed!(BitInstruction::new(boxed!(AddressedByShortSource::new_from_register(register_name)), bit_number, 3));
+}
+
+fn build_register_change_bit_instruction(register_name: RegisterName, bit_number: u8, turn_on: bool) -> Option<Box<dyn Instruction>> {
+    return optional_boxed!(ChangeBitInstruction::new_for_register(register_name, bit_number, turn_on));
+}
+
+fn build_memory_change_bit_instruction(register_name: DoubleRegisterName, bit_number: u8, turn_on: bool) -> Option<Box<dyn Instruction>> {
+    return optional_boxed!(ChangeBitInstruction::new_for_memory(register_name, bit_number, turn_on));
 }
 
 
 pub fn load_instruction(instruction_byte: u8) -> Option<Box<dyn Instruction>> {
     return match instruction_byte {
+        // Test Bit
         // B Register
         0x40 => build_register_bit_instruction(RegisterName::B, 0),
         0x50 => build_register_bit_instruction(RegisterName::B, 2),
@@ -71,14 +81,14 @@ pub fn load_instruction(instruction_byte: u8) -> Option<Box<dyn Instruction>> {
         0x6D => build_register_bit_instruction(RegisterName::L, 5),
         0x7D => build_register_bit_instruction(RegisterName::L, 7),
         // (HL) Register
-        //0x46 => build_bit_instruction(RegisterName::L, 0),
-        //0x56 => build_bit_instruction(RegisterName::L, 2),
-        //0x66 => build_bit_instruction(RegisterName::L, 4),
-        //0x76 => build_bit_instruction(RegisterName::L, 6),
-        //0x4E => build_bit_instruction(RegisterName::L, 1),
-        //0x5E => build_bit_instruction(RegisterName::L, 3),
-        //0x6E => build_bit_instruction(RegisterName::L, 5),
-        //0x7E => build_bit_instruction(RegisterName::L, 7),
+        0x46 => build_memory_bit_instruction(DoubleRegisterName::HL, 0),
+        0x56 => build_memory_bit_instruction(DoubleRegisterName::HL, 2),
+        0x66 => build_memory_bit_instruction(DoubleRegisterName::HL, 4),
+        0x76 => build_memory_bit_instruction(DoubleRegisterName::HL, 6),
+        0x4E => build_memory_bit_instruction(DoubleRegisterName::HL, 1),
+        0x5E => build_memory_bit_instruction(DoubleRegisterName::HL, 3),
+        0x6E => build_memory_bit_instruction(DoubleRegisterName::HL, 5),
+        0x7E => build_memory_bit_instruction(DoubleRegisterName::HL, 7),
         // A Register
         0x47 => build_register_bit_instruction(RegisterName::A, 0),
         0x57 => build_register_bit_instruction(RegisterName::A, 2),
@@ -88,6 +98,152 @@ pub fn load_instruction(instruction_byte: u8) -> Option<Box<dyn Instruction>> {
         0x5F => build_register_bit_instruction(RegisterName::A, 3),
         0x6F => build_register_bit_instruction(RegisterName::A, 5),
         0x7F => build_register_bit_instruction(RegisterName::A, 7),
+        // Reset Bit
+        // B Register
+        0x80 => build_register_change_bit_instruction(RegisterName::B, 0, false),
+        0x90 => build_register_change_bit_instruction(RegisterName::B, 2, false),
+        0xA0 => build_register_change_bit_instruction(RegisterName::B, 4, false),
+        0xB0 => build_register_change_bit_instruction(RegisterName::B, 6, false),
+        0x88 => build_register_change_bit_instruction(RegisterName::B, 1, false),
+        0x98 => build_register_change_bit_instruction(RegisterName::B, 3, false),
+        0xA8 => build_register_change_bit_instruction(RegisterName::B, 5, false),
+        0xB8 => build_register_change_bit_instruction(RegisterName::B, 7, false),
+        // C Register
+        0x81 => build_register_change_bit_instruction(RegisterName::C, 0, false),
+        0x91 => build_register_change_bit_instruction(RegisterName::C, 2, false),
+        0xA1 => build_register_change_bit_instruction(RegisterName::C, 4, false),
+        0xB1 => build_register_change_bit_instruction(RegisterName::C, 6, false),
+        0x89 => build_register_change_bit_instruction(RegisterName::C, 1, false),
+        0x99 => build_register_change_bit_instruction(RegisterName::C, 3, false),
+        0xA9 => build_register_change_bit_instruction(RegisterName::C, 5, false),
+        0xB9 => build_register_change_bit_instruction(RegisterName::C, 7, false),
+        // D Register
+        0x82 => build_register_change_bit_instruction(RegisterName::D, 0, false),
+        0x92 => build_register_change_bit_instruction(RegisterName::D, 2, false),
+        0xA2 => build_register_change_bit_instruction(RegisterName::D, 4, false),
+        0xB2 => build_register_change_bit_instruction(RegisterName::D, 6, false),
+        0x8A => build_register_change_bit_instruction(RegisterName::D, 1, false),
+        0x9A => build_register_change_bit_instruction(RegisterName::D, 3, false),
+        0xAA => build_register_change_bit_instruction(RegisterName::D, 5, false),
+        0xBA => build_register_change_bit_instruction(RegisterName::D, 7, false),
+        // E Register
+        0x83 => build_register_change_bit_instruction(RegisterName::E, 0, false),
+        0x93 => build_register_change_bit_instruction(RegisterName::E, 2, false),
+        0xA3 => build_register_change_bit_instruction(RegisterName::E, 4, false),
+        0xB3 => build_register_change_bit_instruction(RegisterName::E, 6, false),
+        0x8B => build_register_change_bit_instruction(RegisterName::E, 1, false),
+        0x9B => build_register_change_bit_instruction(RegisterName::E, 3, false),
+        0xAB => build_register_change_bit_instruction(RegisterName::E, 5, false),
+        0xBB => build_register_change_bit_instruction(RegisterName::E, 7, false),
+        // H Register
+        0x84 => build_register_change_bit_instruction(RegisterName::H, 0, false),
+        0x94 => build_register_change_bit_instruction(RegisterName::H, 2, false),
+        0xA4 => build_register_change_bit_instruction(RegisterName::H, 4, false),
+        0xB4 => build_register_change_bit_instruction(RegisterName::H, 6, false),
+        0x8C => build_register_change_bit_instruction(RegisterName::H, 1, false),
+        0x9C => build_register_change_bit_instruction(RegisterName::H, 3, false),
+        0xAC => build_register_change_bit_instruction(RegisterName::H, 5, false),
+        0xBC => build_register_change_bit_instruction(RegisterName::H, 7, false),
+        // L Register
+        0x85 => build_register_change_bit_instruction(RegisterName::L, 0, false),
+        0x95 => build_register_change_bit_instruction(RegisterName::L, 2, false),
+        0xA5 => build_register_change_bit_instruction(RegisterName::L, 4, false),
+        0xB5 => build_register_change_bit_instruction(RegisterName::L, 6, false),
+        0x8D => build_register_change_bit_instruction(RegisterName::L, 1, false),
+        0x9D => build_register_change_bit_instruction(RegisterName::L, 3, false),
+        0xAD => build_register_change_bit_instruction(RegisterName::L, 5, false),
+        0xBD => build_register_change_bit_instruction(RegisterName::L, 7, false),
+        // (HL) Register
+        0x86 => build_memory_change_bit_instruction(DoubleRegisterName::HL, 0, false),
+        0x96 => build_memory_change_bit_instruction(DoubleRegisterName::HL, 2, false),
+        0xA6 => build_memory_change_bit_instruction(DoubleRegisterName::HL, 4, false),
+        0xB6 => build_memory_change_bit_instruction(DoubleRegisterName::HL, 6, false),
+        0x8E => build_memory_change_bit_instruction(DoubleRegisterName::HL, 1, false),
+        0x9E => build_memory_change_bit_instruction(DoubleRegisterName::HL, 3, false),
+        0xAE => build_memory_change_bit_instruction(DoubleRegisterName::HL, 5, false),
+        0xBE => build_memory_change_bit_instruction(DoubleRegisterName::HL, 7, false),
+        // A Register
+        0x87 => build_register_change_bit_instruction(RegisterName::A, 0, false),
+        0x97 => build_register_change_bit_instruction(RegisterName::A, 2, false),
+        0xA7 => build_register_change_bit_instruction(RegisterName::A, 4, false),
+        0xB7 => build_register_change_bit_instruction(RegisterName::A, 6, false),
+        0x8F => build_register_change_bit_instruction(RegisterName::A, 1, false),
+        0x9F => build_register_change_bit_instruction(RegisterName::A, 3, false),
+        0xAF => build_register_change_bit_instruction(RegisterName::A, 5, false),
+        0xBF => build_register_change_bit_instruction(RegisterName::A, 7, false),
+        // Set Bit
+        // B Register
+        0xC0 => build_register_change_bit_instruction(RegisterName::B, 0, true),
+        0xD0 => build_register_change_bit_instruction(RegisterName::B, 2, true),
+        0xE0 => build_register_change_bit_instruction(RegisterName::B, 4, true),
+        0xF0 => build_register_change_bit_instruction(RegisterName::B, 6, true),
+        0xC8 => build_register_change_bit_instruction(RegisterName::B, 1, true),
+        0xD8 => build_register_change_bit_instruction(RegisterName::B, 3, true),
+        0xE8 => build_register_change_bit_instruction(RegisterName::B, 5, true),
+        0xF8 => build_register_change_bit_instruction(RegisterName::B, 7, true),
+        // C Register
+        0xC1 => build_register_change_bit_instruction(RegisterName::C, 0, true),
+        0xD1 => build_register_change_bit_instruction(RegisterName::C, 2, true),
+        0xE1 => build_register_change_bit_instruction(RegisterName::C, 4, true),
+        0xF1 => build_register_change_bit_instruction(RegisterName::C, 6, true),
+        0xC9 => build_register_change_bit_instruction(RegisterName::C, 1, true),
+        0xD9 => build_register_change_bit_instruction(RegisterName::C, 3, true),
+        0xE9 => build_register_change_bit_instruction(RegisterName::C, 5, true),
+        0xF9 => build_register_change_bit_instruction(RegisterName::C, 7, true),
+        // D Register
+        0xC2 => build_register_change_bit_instruction(RegisterName::D, 0, true),
+        0xD2 => build_register_change_bit_instruction(RegisterName::D, 2, true),
+        0xE2 => build_register_change_bit_instruction(RegisterName::D, 4, true),
+        0xF2 => build_register_change_bit_instruction(RegisterName::D, 6, true),
+        0xCA => build_register_change_bit_instruction(RegisterName::D, 1, true),
+        0xDA => build_register_change_bit_instruction(RegisterName::D, 3, true),
+        0xEA => build_register_change_bit_instruction(RegisterName::D, 5, true),
+        0xFA => build_register_change_bit_instruction(RegisterName::D, 7, true),
+        // E Register
+        0xC3 => build_register_change_bit_instruction(RegisterName::E, 0, true),
+        0xD3 => build_register_change_bit_instruction(RegisterName::E, 2, true),
+        0xE3 => build_register_change_bit_instruction(RegisterName::E, 4, true),
+        0xF3 => build_register_change_bit_instruction(RegisterName::E, 6, true),
+        0xCB => build_register_change_bit_instruction(RegisterName::E, 1, true),
+        0xDB => build_register_change_bit_instruction(RegisterName::E, 3, true),
+        0xEB => build_register_change_bit_instruction(RegisterName::E, 5, true),
+        0xFB => build_register_change_bit_instruction(RegisterName::E, 7, true),
+        // H Register
+        0xC4 => build_register_change_bit_instruction(RegisterName::H, 0, true),
+        0xD4 => build_register_change_bit_instruction(RegisterName::H, 2, true),
+        0xE4 => build_register_change_bit_instruction(RegisterName::H, 4, true),
+        0xF4 => build_register_change_bit_instruction(RegisterName::H, 6, true),
+        0xCC => build_register_change_bit_instruction(RegisterName::H, 1, true),
+        0xDC => build_register_change_bit_instruction(RegisterName::H, 3, true),
+        0xEC => build_register_change_bit_instruction(RegisterName::H, 5, true),
+        0xFC => build_register_change_bit_instruction(RegisterName::H, 7, true),
+        // L Register
+        0xC5 => build_register_change_bit_instruction(RegisterName::L, 0, true),
+        0xD5 => build_register_change_bit_instruction(RegisterName::L, 2, true),
+        0xE5 => build_register_change_bit_instruction(RegisterName::L, 4, true),
+        0xF5 => build_register_change_bit_instruction(RegisterName::L, 6, true),
+        0xCD => build_register_change_bit_instruction(RegisterName::L, 1, true),
+        0xDD => build_register_change_bit_instruction(RegisterName::L, 3, true),
+        0xED => build_register_change_bit_instruction(RegisterName::L, 5, true),
+        0xFD => build_register_change_bit_instruction(RegisterName::L, 7, true),
+        // (HL) Register
+        0xC6 => build_memory_change_bit_instruction(DoubleRegisterName::HL, 0, true),
+        0xD6 => build_memory_change_bit_instruction(DoubleRegisterName::HL, 2, true),
+        0xE6 => build_memory_change_bit_instruction(DoubleRegisterName::HL, 4, true),
+        0xF6 => build_memory_change_bit_instruction(DoubleRegisterName::HL, 6, true),
+        0xCE => build_memory_change_bit_instruction(DoubleRegisterName::HL, 1, true),
+        0xDE => build_memory_change_bit_instruction(DoubleRegisterName::HL, 3, true),
+        0xEE => build_memory_change_bit_instruction(DoubleRegisterName::HL, 5, true),
+        0xFE => build_memory_change_bit_instruction(DoubleRegisterName::HL, 7, true),
+        // A Register
+        0xC7 => build_register_change_bit_instruction(RegisterName::A, 0, true),
+        0xD7 => build_register_change_bit_instruction(RegisterName::A, 2, true),
+        0xE7 => build_register_change_bit_instruction(RegisterName::A, 4, true),
+        0xF7 => build_register_change_bit_instruction(RegisterName::A, 6, true),
+        0xCF => build_register_change_bit_instruction(RegisterName::A, 1, true),
+        0xDF => build_register_change_bit_instruction(RegisterName::A, 3, true),
+        0xEF => build_register_change_bit_instruction(RegisterName::A, 5, true),
+        0xFF => build_register_change_bit_instruction(RegisterName::A, 7, true),
         _ => None,
     };
 }
@@ -95,6 +251,7 @@ pub fn load_instruction(instruction_byte: u8) -> Option<Box<dyn Instruction>> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cpu::testing::build_test_instruction_context;
     
     #[test]
     fn test_load_instruction_returns_instruction() {
@@ -104,4 +261,28 @@ mod tests {
         
         // Not sure if there'e anything I can assert on
     }
+    
+    #[test]
+    fn test_load_instruction_bit_instruction_tests_requested_bit() {
+        const BIT_4_A_INSTRUCTION: u8 = 0x67;
+        let mut context = build_test_instruction_context();
+        context.registers_mut().a.set(0x10);
+        
+        let instruction = load_instruction(BIT_4_A_INSTRUCTION).unwrap();
+        instruction.run(&mut context);
+        
+        assert_eq!(context.registers().zero_flag.get(), false);
+    }
+    
+    #[test]
+    fn test_load_instruction_set_instruction_sets_requested_bit() {
+        const SET_3_B_INSTRUCTION: u8 = 0xD8;
+        let mut context = build_test_instruction_context();
+        context.registers_mut().b.set(0x00);
+        
+        let instruction = load_instruction(SET_3_B_INSTRUCTION).unwrap();
+        instruction.run(&mut context);
+        
+        assert_eq!(context.registers().b.get(), 0x08);
+    }
 }
@@ -1,2 +1,3 @@
 pub mod bit_instruction;
+pub mod change_bit_instruction;
 pub mod instructions;
@@ -4,13 +4,14 @@ use super::super::sources::{ByteSource, RegisterSource};
 use super::super::super::instruction_context::InstructionContext;
 use super::super::super::registers::RegisterName;
 
-type BinaryByteOpFn = fn(&mut InstructionContext, u8, u8) -> u8;
+pub type BinaryByteOpFn = fn(&mut InstructionContext, u8, u8) -> u8;
 
 pub struct BinaryByteOp {
     left_source: Box<dyn ByteSource>,
     right_source: Box<dyn ByteSource>,
     op: BinaryByteOpFn,
     destination: Box<dyn ByteDestination>,
+    cycles: u8,
 }
 
 impl BinaryByteOp {
@@ -18,33 +19,38 @@ impl BinaryByteOp {
             left_source: Box<dyn ByteSource>,
             right_source: Box<dyn ByteSource>,
             op: BinaryByteOpFn,
-            destination: Box<dyn ByteDestination>) -> BinaryByteOp {
+            destination: Box<dyn ByteDestination>,
+            cycles: u8) -> BinaryByteOp {
 		return BinaryByteOp {
             left_source: left_source,
             right_source: right_source,
             op: op,
             destination: destination,
+            cycles: cycles,
         };
 	}
     
 	pub fn new_inplace_a_op(
             right_source: Box<dyn ByteSource>,
-            op: BinaryByteOpFn,) -> BinaryByteOp {
+            op: BinaryByteOpFn,
+            cycles: u8) -> BinaryByteOp {
 		return BinaryByteOp {
             left_source: Box::new(RegisterSource::new(RegisterName::A)),
             right_source: right_source,
             op: op,
             destination: Box::new(RegisterDestination::new(RegisterName::A)),
+            cycles: cycles,
         };
 	}
 }
 
 impl Instruction for BinaryByteOp {
-	fn run(&self, context: &mut InstructionContext) {
+	fn run(&self, context: &mut InstructionContext) -> u8 {
         let left_value = self.left_source.read(context);
         let right_value = self.right_source.read(context);
         let new_value = (self.op)(context, left_value, right_value);
         self.destination.assign(context, new_value);
+        return self.cycles;
 	}
 }
 
@@ -60,6 +66,7 @@ mod tests {
     
     #[test]
     fn test_run_calls_sources_op_and_destination() {
+        const CYCLES: u8 = 1;
         const INITIAL_A: u8 = 0x12;
         const INITIAL_B: u8 = 0x56;
         const OP_RESULT: u8 = INITIAL_A + INITIAL_B;
@@ -73,10 +80,11 @@ mod tests {
         let right_source = RegisterSource::new(RegisterName::B);
         let destination = RegisterDestination::new(RegisterName::C);
         
-        let instruction = BinaryByteOp::new(Box::new(left_source), Box::new(right_source), fake_add_op, Box::new(destination));
+        let instruction = BinaryByteOp::new(Box::new(left_source), Box::new(right_source), fake_add_op, Box::new(destination), CYCLES);
         
-        instruction.run(&mut context);
+        let cycles = instruction.run(&mut context);
         
         assert_eq!(as_hex!(context.registers().c), as_hex!(OP_RESULT));
+        assert_eq!(cycles, CYCLES);
     }
 }
@@ -4,6 +4,7 @@ pub mod unary_short_op;
 mod no_op;
 
 pub use binary_byte_op::BinaryByteOp;
+pub use binary_byte_op::BinaryByteOpFn;
 pub use unary_byte_op::UnaryByteOp;
 pub use unary_byte_op::UnaryByteOpFn;
 pub use unary_short_op::UnaryShortOp;
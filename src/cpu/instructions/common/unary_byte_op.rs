@@ -10,36 +10,42 @@ pub struct UnaryByteOp {
     source: Box<dyn ByteSource>,
     op: UnaryByteOpFn,
     destination: Box<dyn ByteDestination>,
+    cycles: u8,
 }
 
 impl UnaryByteOp {
 	pub fn new(
             source: Box<dyn ByteSource>,
             op: UnaryByteOpFn,
-            destination: Box<dyn ByteDestination>) -> UnaryByteOp {
+            destination: Box<dyn ByteDestination>,
+            cycles: u8) -> UnaryByteOp {
 		return UnaryByteOp {
             source: source,
             op: op,
             destination: destination,
+            cycles: cycles,
         };
 	}
     
 	pub fn new_no_op(
             source: Box<dyn ByteSource>,
-            destination: Box<dyn ByteDestination>) -> UnaryByteOp {
+            destination: Box<dyn ByteDestination>,
+            cycles: u8) -> UnaryByteOp {
 		return UnaryByteOp {
             source: source,
             op: byte_no_op,
             destination: destination,
+            cycles: cycles,
         };
 	}
 }
 
 impl Instruction for UnaryByteOp {
-	fn run(&self, context: &mut InstructionContext) {
+	fn run(&self, context: &mut InstructionContext) -> u8 {
         let current_value = self.source.read(context);
         let new_value = (self.op)(context, current_value);
         self.destination.assign(context, new_value);
+        return self.cycles;
 	}
 }
 
@@ -58,6 +64,7 @@ mod tests {
     
     #[test]
     fn test_run_calls_source_op_and_destination() {
+        const CYCLES: u8 = 3;
         const INITIAL_A: u8 = 0x12;
         const OP_RESULT: u8 = INITIAL_A + 1;
 
@@ -68,10 +75,11 @@ mod tests {
         let source = RegisterSource::new(RegisterName::A);
         let destination = RegisterDestination::new(RegisterName::C);
         
-        let instruction = UnaryByteOp::new(boxed!(source), fake_inc_op, boxed!(destination));
+        let instruction = UnaryByteOp::new(boxed!(source), fake_inc_op, boxed!(destination), CYCLES);
         
-        instruction.run(&mut context);
+        let cycles = instruction.run(&mut context);
         
         assert_eq!(as_hex!(context.registers().c), as_hex!(OP_RESULT));
+        assert_eq!(cycles, CYCLES);
     }
 }
@@ -10,36 +10,42 @@ pub struct UnaryShortOp {
     source: Box<dyn ShortSource>,
     op: UnaryShortOpFn,
     destination: Box<dyn ShortDestination>,
+    cycles: u8,
 }
 
 impl UnaryShortOp {
 	pub fn new(
             source: Box<dyn ShortSource>,
             op: UnaryShortOpFn,
-            destination: Box<dyn ShortDestination>) -> UnaryShortOp {
+            destination: Box<dyn ShortDestination>,
+            cycles: u8) -> UnaryShortOp {
 		return UnaryShortOp {
             source: source,
             op: op,
             destination: destination,
+            cycles: cycles,
         };
 	}
     
 	pub fn new_no_op(
             source: Box<dyn ShortSource>,
-            destination: Box<dyn ShortDestination>) -> UnaryShortOp {
+            destination: Box<dyn ShortDestination>,
+            cycles: u8) -> UnaryShortOp {
 		return UnaryShortOp {
             source: source,
             op: short_no_op,
             destination: destination,
+            cycles: cycles,
         };
 	}
 }
 
 impl Instruction for UnaryShortOp {
-	fn run(&self, context: &mut InstructionContext) {
+	fn run(&self, context: &mut InstructionContext) -> u8 {
         let current_value = self.source.read(context);
         let new_value = (self.op)(context, current_value);
         self.destination.assign(context, new_value);
+        return self.cycles;
 	}
 }
 
@@ -58,7 +64,8 @@ mod tests {
     
     #[test]
     fn test_run_calls_source_op_and_destination() {
-        const COUNTER: u16 = 0x01;
+        const CYCLES: u8 = 3;
+        const COUNTER: u16 = 0xC001;
         const SOURCE_VALUE: u16 = 0x1234;
         const OP_RESULT: u16 = SOURCE_VALUE + 1;
 
@@ -70,10 +77,11 @@ mod tests {
         let source = ConstantShortSource::new();
         let destination = StackPointerDestination::new();
         
-        let instruction = UnaryShortOp::new(boxed!(source), fake_inc_op, boxed!(destination));
+        let instruction = UnaryShortOp::new(boxed!(source), fake_inc_op, boxed!(destination), CYCLES);
         
-        instruction.run(&mut context);
+        let cycles = instruction.run(&mut context);
         
         assert_eq!(as_hex!(context.stack_mut().get_pointer()), as_hex!(OP_RESULT));
+        assert_eq!(cycles, CYCLES);
     }
 }
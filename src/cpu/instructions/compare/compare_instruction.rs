@@ -7,23 +7,25 @@ use crate::{boxed};
 
 pub struct CompareInstruction {
     source: Box<dyn ByteSource>,
+    cycles: u8,
 }
 
 impl CompareInstruction {
-	pub fn new(source: Box<dyn ByteSource>) -> CompareInstruction {
-		return CompareInstruction {source: source};
+	pub fn new(source: Box<dyn ByteSource>, cycles: u8) -> CompareInstruction {
+		return CompareInstruction {source: source, cycles: cycles};
 	}
 
 	pub fn new_for_register(name: RegisterName) -> CompareInstruction {
-		return CompareInstruction::new(boxed!(RegisterSource::new(name)));
+		return CompareInstruction::new(boxed!(RegisterSource::new(name)), 1);
 	}
 }
 
 impl Instruction for CompareInstruction {
-	fn run(&self, context: &mut InstructionContext) {
+	fn run(&self, context: &mut InstructionContext) -> u8 {
         let a_value = context.registers().a.get();
         let source_value = self.source.read(context);
         subtract(context, a_value, source_value);
+        return self.cycles;
 	}
 }
 
@@ -9,11 +9,11 @@ fn build_compare_instruction(source_name: RegisterName) -> Option<Box<dyn Instru
 }
 
 fn build_compare_instruction_from_memory() -> Option<Box<dyn Instruction>> {
-    return optional_boxed!(CompareInstruction::new(boxed!(AddressedByShortSource::new_from_register(DoubleRegisterName::HL))));
+    return optional_boxed!(CompareInstruction::new(boxed!(AddressedByShortSource::new_from_register(DoubleRegisterName::HL)), 2));
 }
 
 fn build_compare_instruction_from_constant_byte() -> Option<Box<dyn Instruction>> {
-    return optional_boxed!(CompareInstruction::new(boxed!(ConstantByteSource::new())));
+    return optional_boxed!(CompareInstruction::new(boxed!(ConstantByteSource::new()), 2));
 }
 
 pub fn load_instruction(instruction_byte: u8) -> Option<Box<dyn Instruction>> {
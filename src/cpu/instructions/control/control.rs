@@ -0,0 +1,77 @@
+use super::super::super::InstructionContext;
+
+pub fn no_op(_context: &mut InstructionContext) {
+}
+
+pub fn halt(context: &mut InstructionContext) {
+    context.state_mut().halt();
+}
+
+pub fn stop(context: &mut InstructionContext) {
+    // STOP is encoded as two bytes, the second is always ignored
+    context.program_mut().read_next_byte();
+    context.state_mut().stop();
+}
+
+pub fn disable_interrupts(context: &mut InstructionContext) {
+    context.state_mut().disable_interrupts();
+}
+
+pub fn enable_interrupts(context: &mut InstructionContext) {
+    context.state_mut().schedule_interrupts_enable();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpu::testing::build_test_instruction_context;
+    
+    #[test]
+    fn test_halt_halts_cpu() {
+        let mut context = build_test_instruction_context();
+        
+        halt(&mut context);
+        
+        assert_eq!(context.state().is_halted(), true);
+    }
+    
+    #[test]
+    fn test_stop_stops_cpu() {
+        let mut context = build_test_instruction_context();
+        
+        stop(&mut context);
+        
+        assert_eq!(context.state().is_stopped(), true);
+    }
+    
+    #[test]
+    fn test_stop_skips_next_byte() {
+        const COUNTER: u16 = 0x12;
+        let mut context = build_test_instruction_context();
+        context.program_mut().set_counter(COUNTER);
+        
+        stop(&mut context);
+        
+        assert_eq!(context.program().get_counter(), COUNTER+1);
+    }
+    
+    #[test]
+    fn test_disable_interrupts_disables_interrupts() {
+        let mut context = build_test_instruction_context();
+        context.state_mut().enable_interrupts();
+        
+        disable_interrupts(&mut context);
+        
+        assert_eq!(context.state().are_interrupts_enabled(), false);
+    }
+    
+    #[test]
+    fn test_enable_interrupts_only_schedules_enable() {
+        let mut context = build_test_instruction_context();
+        
+        enable_interrupts(&mut context);
+        
+        assert_eq!(context.state().are_interrupts_enabled(), false);
+        assert_eq!(context.state().is_interrupts_enable_scheduled(), true);
+    }
+}
@@ -0,0 +1,43 @@
+use super::super::instruction::Instruction;
+use super::super::super::InstructionContext;
+
+const CYCLES: u8 = 1;
+
+pub type ControlFn = fn(&mut InstructionContext);
+
+pub struct ControlInstruction {
+    op: ControlFn,
+}
+
+impl ControlInstruction {
+	pub fn new(op: ControlFn) -> ControlInstruction {
+		return ControlInstruction {op: op};
+	}
+}
+
+impl Instruction for ControlInstruction {
+	fn run(&self, context: &mut InstructionContext) -> u8 {
+        (self.op)(context);
+        return CYCLES;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpu::testing::build_test_instruction_context;
+    
+    fn fake_halt_op(context: &mut InstructionContext) {
+        context.state_mut().halt();
+    }
+    
+    #[test]
+    fn test_run_calls_op() {
+        let mut context = build_test_instruction_context();
+        
+        let instruction = ControlInstruction::new(fake_halt_op);
+        instruction.run(&mut context);
+        
+        assert_eq!(context.state().is_halted(), true);
+    }
+}
@@ -0,0 +1,33 @@
+use super::control::{disable_interrupts, enable_interrupts, halt, no_op, stop};
+use super::control_instruction::{ControlFn, ControlInstruction};
+use super::super::instruction::Instruction;
+use crate::{boxed, optional_boxed};
+
+fn build_control_instruction(op: ControlFn) -> Option<Box<dyn Instruction>> {
+    return optional_boxed!(ControlInstruction::new(op));
+}
+
+pub fn load_instruction(instruction_byte: u8) -> Option<Box<dyn Instruction>> {
+    return match instruction_byte {
+        0x00 => build_control_instruction(no_op),
+        0x10 => build_control_instruction(stop),
+        0x76 => build_control_instruction(halt),
+        0xF3 => build_control_instruction(disable_interrupts),
+        0xFB => build_control_instruction(enable_interrupts),
+        _ => None,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_load_instruction_returns_instruction() {
+        const NOP_INSTRUCTION: u8 = 0x00;
+        
+        load_instruction(NOP_INSTRUCTION);
+        
+        // Not sure if there'e anything I can assert on
+    }
+}
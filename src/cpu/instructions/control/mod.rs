@@ -0,0 +1,4 @@
+pub mod instructions;
+
+mod control;
+mod control_instruction;
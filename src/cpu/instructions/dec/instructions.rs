@@ -11,7 +11,8 @@ fn build_dec_register(register: RegisterName) -> Option<Box<dyn Instruction>> {
         UnaryByteOp::new(
             boxed!(RegisterSource::new(register)),
             dec,
-            boxed!(RegisterDestination::new(register))
+            boxed!(RegisterDestination::new(register)),
+            1
         )
     );
 }
@@ -21,7 +22,8 @@ fn build_dec_memory_location(register: DoubleRegisterName) -> Option<Box<dyn Ins
         UnaryByteOp::new(
             boxed!(AddressedByShortSource::new_from_register(register)),
             dec,
-            boxed!(AddressedByShortDestination::new_from_register(register))
+            boxed!(AddressedByShortDestination::new_from_register(register)),
+            3
         )
     );
 }
@@ -31,7 +33,8 @@ fn build_dec_double_register(register: DoubleRegisterName) -> Option<Box<dyn Ins
         UnaryShortOp::new(
             boxed!(DoubleRegisterSource::new(register)),
             dec_short,
-            boxed!(DoubleRegisterDestination::new(register))
+            boxed!(DoubleRegisterDestination::new(register)),
+            2
         )
     );
 }
@@ -41,7 +44,8 @@ fn build_dec_stack_pointer() -> Option<Box<dyn Instruction>> {
         UnaryShortOp::new(
             boxed!(StackPointerSource::new()),
             dec_short,
-            boxed!(StackPointerDestination::new())
+            boxed!(StackPointerDestination::new()),
+            2
         )
     );
 }
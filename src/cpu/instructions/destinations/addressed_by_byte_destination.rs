@@ -32,7 +32,7 @@ mod tests {
     #[test]
     fn test_assign_assigns_memory() {
         const EXPECTED_VALUE: u8 = 0xAB;
-        const SUBADDRESS_VALUE: u8 = 0x78;
+        const SUBADDRESS_VALUE: u8 = 0x88;
         const EXPECTED_ADDRESS: u16 = build_u16!(0xFF, SUBADDRESS_VALUE);
         let mut context = build_test_instruction_context();
         context.registers_mut().a.set(SUBADDRESS_VALUE);
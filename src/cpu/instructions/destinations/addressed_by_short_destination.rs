@@ -52,7 +52,7 @@ mod tests {
     
     #[test]
     fn test_assign_assigns_memory() {
-        const EXPECTED_ADDRESS: u16 = 0xFEDC;
+        const EXPECTED_ADDRESS: u16 = 0xDEDC;
         const EXPECTED_VALUE: u8 = 0x78;
         let mut context = build_test_instruction_context();
         context.registers_mut().hl.set(EXPECTED_ADDRESS);
@@ -66,7 +66,7 @@ mod tests {
     
     #[test]
     fn test_assign_with_follow_up_assigns_memory() {
-        const EXPECTED_ADDRESS: u16 = 0xFEDC;
+        const EXPECTED_ADDRESS: u16 = 0xDEDC;
         const EXPECTED_VALUE: u8 = 0x78;
         let mut context = build_test_instruction_context();
         context.registers_mut().hl.set(EXPECTED_ADDRESS);
@@ -80,7 +80,7 @@ mod tests {
     
     #[test]
     fn test_assign_with_follow_up_fn() {
-        const ORIGINAL_ADDRESS: u16 = 0xFEDC;
+        const ORIGINAL_ADDRESS: u16 = 0xDEDC;
         const EXPECTED_ADDRESS: u16 = ORIGINAL_ADDRESS-1;
         let mut context = build_test_instruction_context();
         context.registers_mut().hl.set(ORIGINAL_ADDRESS);
@@ -1,5 +1,5 @@
 use super::super::super::InstructionContext;
 
-pub trait ByteDestination {
+pub trait ByteDestination: Send + Sync {
     fn assign(&self, context: &mut InstructionContext, new_value: u8);
 }
@@ -0,0 +1,47 @@
+use super::short_destination::ShortDestination;
+use super::super::sources::{ConstantShortSource, ShortSource};
+use super::super::super::instruction_context::InstructionContext;
+use crate::boxed;
+
+pub struct ShortAddressedByShortDestination {
+    _address_source: Box<dyn ShortSource>,
+}
+
+impl ShortAddressedByShortDestination {
+	pub fn new(source: Box<dyn ShortSource>) -> ShortAddressedByShortDestination {
+		return ShortAddressedByShortDestination {_address_source: source};
+	}
+    
+	pub fn new_from_constant() -> ShortAddressedByShortDestination {
+		return ShortAddressedByShortDestination::new(boxed!(ConstantShortSource::new()));
+	}
+}
+
+impl ShortDestination for ShortAddressedByShortDestination {
+	fn assign(&self, context: &mut InstructionContext, new_value: u16) {
+        let address = self._address_source.read(context);
+        context.memory_mut().write_short(address, new_value);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::as_hex;
+    use crate::cpu::testing::build_test_instruction_context;
+    
+    #[test]
+    fn test_assign_from_constant_assigns_memory() {
+        const COUNTER: u16 = 0xC010;
+        const EXPECTED_ADDRESS: u16 = 0xDEDC;
+        const EXPECTED_VALUE: u16 = 0x5678;
+        let mut context = build_test_instruction_context();
+        context.program_mut().set_counter(COUNTER);
+        context.memory_mut().write_short(COUNTER, EXPECTED_ADDRESS);
+        let destination = ShortAddressedByShortDestination::new_from_constant();
+        
+        destination.assign(&mut context, EXPECTED_VALUE);
+        
+        assert_eq!(as_hex!(context.memory().read_short(EXPECTED_ADDRESS)), as_hex!(EXPECTED_VALUE));
+    }
+}
@@ -11,7 +11,8 @@ fn build_inc_register(register: RegisterName) -> Option<Box<dyn Instruction>> {
         UnaryByteOp::new(
             boxed!(RegisterSource::new(register)),
             inc,
-            boxed!(RegisterDestination::new(register))
+            boxed!(RegisterDestination::new(register)),
+            1
         )
     );
 }
@@ -21,7 +22,8 @@ fn build_inc_memory_location(register: DoubleRegisterName) -> Option<Box<dyn Ins
         UnaryByteOp::new(
             boxed!(AddressedByShortSource::new_from_register(register)),
             inc,
-            boxed!(AddressedByShortDestination::new_from_register(register))
+            boxed!(AddressedByShortDestination::new_from_register(register)),
+            3
         )
     );
 }
@@ -31,7 +33,8 @@ fn build_inc_double_register(register: DoubleRegisterName) -> Option<Box<dyn Ins
         UnaryShortOp::new(
             boxed!(DoubleRegisterSource::new(register)),
             inc_short,
-            boxed!(DoubleRegisterDestination::new(register))
+            boxed!(DoubleRegisterDestination::new(register)),
+            2
         )
     );
 }
@@ -41,7 +44,8 @@ fn build_inc_stack_pointer() -> Option<Box<dyn Instruction>> {
         UnaryShortOp::new(
             boxed!(StackPointerSource::new()),
             inc_short,
-            boxed!(StackPointerDestination::new())
+            boxed!(StackPointerDestination::new()),
+            2
         )
     );
 }
@@ -1,5 +1,7 @@
 use super::super::instruction_context::InstructionContext;
 
-pub trait Instruction {
-    fn run(&self, context: &mut InstructionContext);
+// Instructions live in the shared dispatch tables, so they must be thread safe
+pub trait Instruction: Send + Sync {
+    // Returns the number of M-cycles the instruction took to run
+    fn run(&self, context: &mut InstructionContext) -> u8;
 }
@@ -0,0 +1,65 @@
+use super::instruction::Instruction;
+
+pub type PackageInstructionLoader = fn(u8) -> Option<Box<dyn Instruction>>;
+
+const TABLE_SIZE: usize = 0x100;
+
+pub struct InstructionTable {
+    instructions: Vec<Option<Box<dyn Instruction>>>,
+}
+
+impl InstructionTable {
+    pub fn new(package_instruction_loaders: &[PackageInstructionLoader]) -> InstructionTable {
+        let instructions = (0..TABLE_SIZE).map(
+            |instruction_byte| load_instruction_from_packages(instruction_byte as u8, package_instruction_loaders)
+        ).collect();
+        
+        return InstructionTable {instructions: instructions};
+    }
+    
+    pub fn get(&self, instruction_byte: u8) -> Option<&dyn Instruction> {
+        return self.instructions[instruction_byte as usize].as_deref();
+    }
+}
+
+fn load_instruction_from_packages(instruction_byte: u8, package_instruction_loaders: &[PackageInstructionLoader]) -> Option<Box<dyn Instruction>> {
+    let next_instruction = package_instruction_loaders.iter().find_map(
+        |load_from_package| load_from_package(instruction_byte)
+    );
+    
+    return next_instruction;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::control::instructions::load_instruction;
+    use std::ptr;
+    
+    const NOP_INSTRUCTION: u8 = 0x00;
+    const ADD_INSTRUCTION: u8 = 0x87;
+    
+    #[test]
+    fn test_get_decoded_opcode_returns_instruction() {
+        let table = InstructionTable::new(&[load_instruction]);
+        
+        assert_eq!(table.get(NOP_INSTRUCTION).is_some(), true);
+    }
+    
+    #[test]
+    fn test_get_opcode_missing_from_packages_returns_none() {
+        let table = InstructionTable::new(&[load_instruction]);
+        
+        assert_eq!(table.get(ADD_INSTRUCTION).is_none(), true);
+    }
+    
+    #[test]
+    fn test_get_same_opcode_returns_same_instruction() {
+        let table = InstructionTable::new(&[load_instruction]);
+        
+        let first = table.get(NOP_INSTRUCTION).unwrap();
+        let second = table.get(NOP_INSTRUCTION).unwrap();
+        
+        assert_eq!(ptr::addr_eq(first, second), true);
+    }
+}
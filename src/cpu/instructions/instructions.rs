@@ -1,112 +1,162 @@
+use super::accumulator;
 use super::add;
+use super::and;
 use super::bit;
 use super::compare;
+use super::control;
 use super::dec;
 use super::inc;
 use super::jump;
 use super::load;
+use super::or;
 use super::rotate;
 use super::stack;
 use super::subtract;
 use super::xor;
 use super::instruction::Instruction;
-use super::super::ProgramCounter;
-use crate::as_hex;
-use std::boxed::Box;
-use std::option::Option;
-
-type PackageInstructionLoader = fn(u8) -> Option<Box<(dyn Instruction + 'static)>>;
+use super::instruction_table::{InstructionTable, PackageInstructionLoader};
+use super::super::{DecodeError, ProgramCounter};
+use std::sync::OnceLock;
 
 static PREFIX_INSTRUCTION: u8 = 0xCB;
 
-pub fn load_instruction(program: &mut ProgramCounter) -> Box<dyn Instruction> {
-    
+static STANDARD_INSTRUCTIONS: OnceLock<InstructionTable> = OnceLock::new();
+static PREFIX_INSTRUCTIONS: OnceLock<InstructionTable> = OnceLock::new();
+
+pub fn load_instruction(program: &mut ProgramCounter) -> Result<&'static dyn Instruction, DecodeError> {
+    let address = program.get_counter();
     let instruction_byte = program.read_next_byte();
-    println!("Program Counter: {}, {}", as_hex!(program.get_counter()-1), as_hex!(instruction_byte));
     
     if instruction_byte == PREFIX_INSTRUCTION {
         let instruction_byte = program.read_next_byte();
-        return load_prefix_instruction(instruction_byte);
+        return load_prefix_instruction(address, instruction_byte);
     } else {
-        return load_standard_instruction(instruction_byte);
+        return load_standard_instruction(address, instruction_byte);
+    }
+}
+
+fn load_standard_instruction(address: u16, instruction_byte: u8) -> Result<&'static dyn Instruction, DecodeError> {
+    let next_instruction = standard_instructions().get(instruction_byte);
+    
+    return match next_instruction {
+        Some(instruction) => Ok(instruction),
+        None => Err(DecodeError::IllegalOpcode {address: address, opcode: instruction_byte}),
     }
 }
 
-fn load_standard_instruction(instruction_byte: u8) -> Box<dyn Instruction> {
-    let package_instruction_loaders: Vec<PackageInstructionLoader>
-            = vec![
+fn standard_instructions() -> &'static InstructionTable {
+    return STANDARD_INSTRUCTIONS.get_or_init(|| InstructionTable::new(&standard_instruction_loaders()));
+}
+
+fn standard_instruction_loaders() -> Vec<PackageInstructionLoader> {
+    return vec![
+        accumulator::instructions::load_instruction,
         add::instructions::load_instruction,
+        and::instructions::load_instruction,
         compare::instructions::load_instruction,
+        control::instructions::load_instruction,
         dec::instructions::load_instruction,
         inc::instructions::load_instruction,
         jump::instructions::load_instruction,
         load::instructions::load_instruction,
+        or::instructions::load_instruction,
         rotate::instructions::load_instruction,
         stack::instructions::load_instruction,
         subtract::instructions::load_instruction,
         xor::instructions::load_instruction,
     ];
-    
-    let next_instruction = load_instruction_from_packages(instruction_byte, package_instruction_loaders);
+}
+
+fn load_prefix_instruction(address: u16, instruction_byte: u8) -> Result<&'static dyn Instruction, DecodeError> {
+    let next_instruction = prefix_instructions().get(instruction_byte);
     
     return match next_instruction {
-        Some(instruction) => {
-            instruction
-        },
-        None => panic!("Unknown instruction: {}", as_hex!(instruction_byte)),
+        Some(instruction) => Ok(instruction),
+        None => Err(DecodeError::UnknownPrefixOpcode {address: address, opcode: instruction_byte}),
     }
 }
 
-fn load_prefix_instruction(instruction_byte: u8) -> Box<dyn Instruction> {
-    let package_instruction_loaders: Vec<PackageInstructionLoader> = vec![
+fn prefix_instructions() -> &'static InstructionTable {
+    return PREFIX_INSTRUCTIONS.get_or_init(|| InstructionTable::new(&prefix_instruction_loaders()));
+}
+
+fn prefix_instruction_loaders() -> Vec<PackageInstructionLoader> {
+    return vec![
         bit::instructions::load_instruction,
         rotate::instructions::load_prefix_instruction,
     ];
-    
-    let next_instruction = load_instruction_from_packages(instruction_byte, package_instruction_loaders);
-    
-    return match next_instruction {
-        Some(instruction) => {
-            instruction
-        },
-        None => panic!("Unknown prefix instruction: {}", as_hex!(instruction_byte)),
-    }
-}
-
-fn load_instruction_from_packages(instruction_byte: u8, package_instruction_loaders: Vec<PackageInstructionLoader>) -> Option<Box<dyn Instruction>> {
-    let next_instruction = package_instruction_loaders.iter().find_map(
-        |load_from_package| load_from_package(instruction_byte)
-    );
-    
-    return next_instruction;
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::as_hex;
     use crate::cpu::testing::build_test_program_counter;
     
     #[test]
     fn test_load_instruction_returns_instruction() {
         const ADD_INSTRUCTION: u8 = 0x87;
+        const ADDRESS: u16 = 0xC123;
+        let mut program = build_test_program_counter();
+        program.set_counter(ADDRESS);
+        program._memory.borrow_mut().write_byte(ADDRESS, ADD_INSTRUCTION);
+        
+        let result = load_instruction(&mut program);
+        
+        assert_eq!(result.is_ok(), true);
+        assert_eq!(program.get_counter(), ADDRESS + 1);
+    }
+    
+    #[test]
+    fn test_load_instruction_illegal_opcode_returns_error() {
+        const ILLEGAL_INSTRUCTION: u8 = 0xDD;
+        const ADDRESS: u16 = 0xC123;
         let mut program = build_test_program_counter();
-        program._memory.borrow_mut().write_byte(0x0000, ADD_INSTRUCTION);
+        program.set_counter(ADDRESS);
+        program._memory.borrow_mut().write_byte(ADDRESS, ILLEGAL_INSTRUCTION);
         
-        load_instruction(&mut program);
+        let result = load_instruction(&mut program);
         
-        // Not sure if there'e anything I can assert on
+        assert_eq!(result.err(), Some(DecodeError::IllegalOpcode {address: ADDRESS, opcode: ILLEGAL_INSTRUCTION}));
     }
     
-    // TODO: Uncomment once Prefix has its first actual instruction
-    /*#[test]
+    #[test]
+    fn test_load_standard_instruction_every_documented_opcode_decodes() {
+        const UNDECODED_OPCODES: [u8; 12] = [
+            PREFIX_INSTRUCTION,
+            // Illegal opcodes
+            0xD3, 0xDB, 0xDD, 0xE3, 0xE4, 0xEB, 0xEC, 0xED, 0xF4, 0xFC, 0xFD,
+        ];
+        
+        for instruction_byte in 0x00..=0xFF {
+            let decoded = standard_instructions().get(instruction_byte).is_some();
+            let expected = !UNDECODED_OPCODES.contains(&instruction_byte);
+            
+            assert_eq!(decoded, expected, "Opcode {}", as_hex!(instruction_byte));
+        }
+    }
+    
+    #[test]
+    fn test_load_prefix_instruction_every_opcode_decodes() {
+        for instruction_byte in 0x00..=0xFF {
+            let decoded = prefix_instructions().get(instruction_byte).is_some();
+            
+            assert_eq!(decoded, true, "Prefix opcode {}", as_hex!(instruction_byte));
+        }
+    }
+    
+    #[test]
     fn test_load_instruction_prefix_instruction_returns_instruction() {
         const BIT_INSTRUCTION: u8 = 0x40;
+        const ADDRESS: u16 = 0xC123;
         let mut program = build_test_program_counter();
-        program._memory.borrow_mut().write_byte(0x0000, PREFIX_INSTRUCTION);
-        program._memory.borrow_mut().write_byte(0x0001, BIT_INSTRUCTION);
+        program.set_counter(ADDRESS);
+        program._memory.borrow_mut().write_byte(ADDRESS, PREFIX_INSTRUCTION);
+        program._memory.borrow_mut().write_byte(ADDRESS + 1, BIT_INSTRUCTION);
         
-        load_instruction(&mut program);
+        let result = load_instruction(&mut program);
         
-        // Not sure if there'e anything I can assert on
-    }*/
+        assert_eq!(result.is_ok(), true);
+        assert_eq!(program.get_counter(), ADDRESS + 2);
+    }
 }
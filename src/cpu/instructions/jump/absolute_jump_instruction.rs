@@ -0,0 +1,93 @@
+use super::{JumpConditionFn, jump};
+use super::super::instruction::Instruction;
+use super::super::sources::ShortSource;
+use super::super::super::InstructionContext;
+
+pub struct AbsoluteJumpInstruction {
+    source: Box<dyn ShortSource>,
+    condition: JumpConditionFn,
+    taken_cycles: u8,
+    not_taken_cycles: u8,
+}
+
+impl AbsoluteJumpInstruction {
+	pub fn new(
+            source: Box<dyn ShortSource>,
+            condition: JumpConditionFn,
+            taken_cycles: u8,
+            not_taken_cycles: u8) -> AbsoluteJumpInstruction {
+		return AbsoluteJumpInstruction {
+            source: source,
+            condition: condition,
+            taken_cycles: taken_cycles,
+            not_taken_cycles: not_taken_cycles,
+        };
+	}
+}
+
+impl Instruction for AbsoluteJumpInstruction {
+	fn run(&self, context: &mut InstructionContext) -> u8 {
+        let new_counter = self.source.read(context);
+        let jumped = jump(new_counter, self.condition, context);
+        return if jumped { self.taken_cycles } else { self.not_taken_cycles };
+	}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::conditions::{always};
+    use super::super::super::sources::{ConstantShortSource, DoubleRegisterSource};
+    use super::super::super::super::registers::DoubleRegisterName;
+    use crate::cpu::testing::build_test_instruction_context;
+    use crate::boxed;
+    
+    const TAKEN_CYCLES: u8 = 4;
+    const NOT_TAKEN_CYCLES: u8 = 3;
+    
+    fn invalid_condition(_context: &InstructionContext) -> bool {
+        return false;
+    }
+    
+    #[test]
+    fn test_run_condition_true_jumps_to_address() {
+        const INITIAL_COUNTER: u16 = 0xC00A;
+        const COUNTER_TO_JUMP_TO: u16 = 0xCAB0;
+        let mut context = build_test_instruction_context();
+        context.program_mut().set_counter(INITIAL_COUNTER);
+        context.memory_mut().write_short(INITIAL_COUNTER, COUNTER_TO_JUMP_TO);
+        
+        let instruction = AbsoluteJumpInstruction::new(boxed!(ConstantShortSource::new()), always, TAKEN_CYCLES, NOT_TAKEN_CYCLES);
+        let cycles = instruction.run(&mut context);
+        
+        assert_eq!(context.program().get_counter(), COUNTER_TO_JUMP_TO);
+        assert_eq!(cycles, TAKEN_CYCLES);
+    }
+    
+    #[test]
+    fn test_run_condition_false_skips_address() {
+        const INITIAL_COUNTER: u16 = 0x0A;
+        const COUNTER_TO_JUMP_TO: u16 = 0xCAB0;
+        let mut context = build_test_instruction_context();
+        context.program_mut().set_counter(INITIAL_COUNTER);
+        context.memory_mut().write_short(INITIAL_COUNTER, COUNTER_TO_JUMP_TO);
+        
+        let instruction = AbsoluteJumpInstruction::new(boxed!(ConstantShortSource::new()), invalid_condition, TAKEN_CYCLES, NOT_TAKEN_CYCLES);
+        let cycles = instruction.run(&mut context);
+        
+        assert_eq!(context.program().get_counter(), INITIAL_COUNTER+2);
+        assert_eq!(cycles, NOT_TAKEN_CYCLES);
+    }
+    
+    #[test]
+    fn test_run_from_register_jumps_to_register_value() {
+        const COUNTER_TO_JUMP_TO: u16 = 0x1234;
+        let mut context = build_test_instruction_context();
+        context.registers_mut().hl.set(COUNTER_TO_JUMP_TO);
+        
+        let instruction = AbsoluteJumpInstruction::new(boxed!(DoubleRegisterSource::new(DoubleRegisterName::HL)), always, 1, 1);
+        instruction.run(&mut context);
+        
+        assert_eq!(context.program().get_counter(), COUNTER_TO_JUMP_TO);
+    }
+}
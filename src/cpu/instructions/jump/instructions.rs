@@ -1,8 +1,10 @@
 use super::JumpConditionFn;
+use super::absolute_jump_instruction::AbsoluteJumpInstruction;
 use super::conditions::{always, is_carry_flag_off, is_carry_flag_on, is_zero_flag_off, is_zero_flag_on};
 use super::jump_instruction::JumpInstruction;
 use super::super::instruction::Instruction;
-use super::super::sources::{ByteSource, ConstantByteSource};
+use super::super::sources::{ByteSource, ConstantByteSource, ConstantShortSource, DoubleRegisterSource};
+use super::super::super::registers::DoubleRegisterName;
 use crate::{boxed, optional_boxed};
 
 fn build_relative_jump_instruction(source: Box<dyn ByteSource>, condition: JumpConditionFn) -> Option<Box<dyn Instruction>> {
@@ -14,6 +16,28 @@ fn build_relative_jump_instruction(source: Box<dyn ByteSource>, condition: JumpC
     );
 }
 
+fn build_absolute_jump_instruction(condition: JumpConditionFn) -> Option<Box<dyn Instruction>> {
+    return optional_boxed!(
+        AbsoluteJumpInstruction::new(
+            boxed!(ConstantShortSource::new()),
+            condition,
+            4,
+            3
+        )
+    );
+}
+
+fn build_register_jump_instruction(register: DoubleRegisterName) -> Option<Box<dyn Instruction>> {
+    return optional_boxed!(
+        AbsoluteJumpInstruction::new(
+            boxed!(DoubleRegisterSource::new(register)),
+            always,
+            1,
+            1
+        )
+    );
+}
+
 pub fn load_instruction(instruction_byte: u8) -> Option<Box<dyn Instruction>> {
     return match instruction_byte {
         // Relative Jumps
@@ -22,6 +46,13 @@ pub fn load_instruction(instruction_byte: u8) -> Option<Box<dyn Instruction>> {
         0x28 => build_relative_jump_instruction(boxed!(ConstantByteSource::new()), is_zero_flag_on),
         0x30 => build_relative_jump_instruction(boxed!(ConstantByteSource::new()), is_carry_flag_off),
         0x38 => build_relative_jump_instruction(boxed!(ConstantByteSource::new()), is_carry_flag_on),
+        // Absolute Jumps
+        0xC3 => build_absolute_jump_instruction(always),
+        0xC2 => build_absolute_jump_instruction(is_zero_flag_off),
+        0xCA => build_absolute_jump_instruction(is_zero_flag_on),
+        0xD2 => build_absolute_jump_instruction(is_carry_flag_off),
+        0xDA => build_absolute_jump_instruction(is_carry_flag_on),
+        0xE9 => build_register_jump_instruction(DoubleRegisterName::HL),
         _ => None,
     };
 }
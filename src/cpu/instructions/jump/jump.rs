@@ -2,16 +2,18 @@ use super::super::super::InstructionContext;
 
 pub type JumpConditionFn = fn(&InstructionContext) -> bool;
 
-pub fn jump(new_counter: u16, condition: JumpConditionFn, context: &mut InstructionContext) {
-    jump_with_extra_work(condition, context, |context_again| {return new_counter});
+pub fn jump(new_counter: u16, condition: JumpConditionFn, context: &mut InstructionContext) -> bool {
+    return jump_with_extra_work(condition, context, |context_again| {return new_counter});
 }
 
-pub fn jump_with_extra_work<F>(condition: JumpConditionFn, context: &mut InstructionContext, work: F)
+pub fn jump_with_extra_work<F>(condition: JumpConditionFn, context: &mut InstructionContext, work: F) -> bool
         where F : Fn(&mut InstructionContext) -> u16 {
     if (condition)(context) {
         let new_counter = work(context);
         context.program_mut().set_counter(new_counter);
+        return true;
     }
+    return false;
 }
 
 #[cfg(test)]
@@ -33,9 +35,10 @@ mod tests {
         let mut context = build_test_instruction_context();
         context.program_mut().set_counter(INITIAL_COUNTER);
         
-        jump(COUNTER_TO_JUMP_TO, always, &mut context);
+        let jumped = jump(COUNTER_TO_JUMP_TO, always, &mut context);
         
         assert_eq!(context.program().get_counter(), COUNTER_TO_JUMP_TO);
+        assert_eq!(jumped, true);
     }
     
     #[test]
@@ -45,8 +48,9 @@ mod tests {
         let mut context = build_test_instruction_context();
         context.program_mut().set_counter(INITIAL_COUNTER);
         
-        jump(COUNTER_TO_JUMP_TO, invalid_condition, &mut context);
+        let jumped = jump(COUNTER_TO_JUMP_TO, invalid_condition, &mut context);
         
         assert_eq!(context.program().get_counter(), INITIAL_COUNTER);
+        assert_eq!(jumped, false);
     }
 }
@@ -4,6 +4,9 @@ use super::super::sources::{ByteSource, RegisterSource};
 use super::super::super::InstructionContext;
 use super::super::super::registers::RegisterName;
 
+const TAKEN_CYCLES: u8 = 3;
+const NOT_TAKEN_CYCLES: u8 = 2;
+
 pub struct JumpInstruction {
     source: Box<dyn ByteSource>,
     condition: JumpConditionFn,
@@ -21,10 +24,11 @@ impl JumpInstruction {
 }
 
 impl Instruction for JumpInstruction {
-	fn run(&self, context: &mut InstructionContext) {
+	fn run(&self, context: &mut InstructionContext) -> u8 {
         let relative_address = self.source.read(context) as i8;
         let new_counter = context.program_mut().get_counter().wrapping_add(relative_address as u16);
-        jump(new_counter, self.condition, context);
+        let jumped = jump(new_counter, self.condition, context);
+        return if jumped { TAKEN_CYCLES } else { NOT_TAKEN_CYCLES };
 	}
 }
 
@@ -42,18 +46,19 @@ mod tests {
     
     #[test]
     fn test_run_condition_true_jumps_to_relative_location() {
-        const INITIAL_COUNTER: u16 = 0x0A + 1;
+        const INITIAL_COUNTER: u16 = 0xC00A + 1;
         const RELATIVE_JUMP: u8 = 0xFB;
-        const EXPECTED_COUNTER: u16 = 0x07;
+        const EXPECTED_COUNTER: u16 = 0xC007;
         let mut context = build_test_instruction_context();
         context.program_mut().set_counter(INITIAL_COUNTER);
         context.memory_mut().write_byte(INITIAL_COUNTER, RELATIVE_JUMP);
         let source = ConstantByteSource::new();
         
         let instruction = JumpInstruction::new(boxed!(source), always);
-        instruction.run(&mut context);
+        let cycles = instruction.run(&mut context);
         
         assert_eq!(context.program().get_counter(), EXPECTED_COUNTER);
+        assert_eq!(cycles, TAKEN_CYCLES);
     }
     
     #[test]
@@ -67,8 +72,9 @@ mod tests {
         let source = ConstantByteSource::new();
         
         let instruction = JumpInstruction::new(boxed!(source), invalid_condition);
-        instruction.run(&mut context);
+        let cycles = instruction.run(&mut context);
         
         assert_eq!(context.program().get_counter(), INITIAL_COUNTER+1);
+        assert_eq!(cycles, NOT_TAKEN_CYCLES);
     }
 }
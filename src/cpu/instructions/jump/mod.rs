@@ -1,4 +1,5 @@
 pub mod instructions;
+mod absolute_jump_instruction;
 mod conditions;
 mod jump;
 mod jump_instruction;
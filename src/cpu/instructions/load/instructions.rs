@@ -1,7 +1,7 @@
 use super::super::instruction::Instruction;
 use super::super::common::{UnaryByteOp, UnaryByteOpFn, UnaryShortOp};
-use super::super::sources::{AddressedByByteSource, AddressedByShortSource, ConstantByteSource, ConstantShortSource, RegisterSource};
-use super::super::destinations::{AddressedByByteDestination, AddressedByShortDestination, DoubleRegisterDestination, RegisterDestination, StackPointerDestination};
+use super::super::sources::{AddressedByByteSource, AddressedByShortSource, ConstantByteSource, ConstantShortSource, DoubleRegisterSource, RegisterSource, StackPointerSource};
+use super::super::destinations::{AddressedByByteDestination, AddressedByShortDestination, DoubleRegisterDestination, RegisterDestination, ShortAddressedByShortDestination, StackPointerDestination};
 use super::super::super::registers::{DoubleRegisterName, RegisterName};
 use crate::{boxed, optional_boxed};
 
@@ -9,7 +9,8 @@ fn build_load_instruction(source_name: RegisterName, destination_name: RegisterN
     return optional_boxed!(
         UnaryByteOp::new_no_op(
             boxed!(RegisterSource::new(source_name)),
-            boxed!(RegisterDestination::new(destination_name))
+            boxed!(RegisterDestination::new(destination_name)),
+            1
         )
     );
 }
@@ -18,7 +19,8 @@ fn build_load_instruction_from_constant_byte(destination_name: RegisterName) ->
     return optional_boxed!(
         UnaryByteOp::new_no_op(
             boxed!(ConstantByteSource::new()),
-            boxed!(RegisterDestination::new(destination_name))
+            boxed!(RegisterDestination::new(destination_name)),
+            2
         )
     );
 }
@@ -27,7 +29,8 @@ fn build_load_into_address_instruction(source_name: RegisterName, destination_na
     return optional_boxed!(
         UnaryByteOp::new_no_op(
             boxed!(RegisterSource::new(source_name)),
-            boxed!(AddressedByShortDestination::new_from_register(destination_name))
+            boxed!(AddressedByShortDestination::new_from_register(destination_name)),
+            2
         )
     );
 }
@@ -36,7 +39,8 @@ fn build_load_into_address_instruction_from_constant_byte(destination_name: Doub
     return optional_boxed!(
         UnaryByteOp::new_no_op(
             boxed!(ConstantByteSource::new()),
-            boxed!(AddressedByShortDestination::new_from_register(destination_name))
+            boxed!(AddressedByShortDestination::new_from_register(destination_name)),
+            3
         )
     );
 }
@@ -45,7 +49,8 @@ fn build_load_from_address_instruction(source_name: DoubleRegisterName, destinat
     return optional_boxed!(
         UnaryByteOp::new_no_op(
             boxed!(AddressedByShortSource::new_from_register(source_name)),
-            boxed!(RegisterDestination::new(destination_name))
+            boxed!(RegisterDestination::new(destination_name)),
+            2
         )
     );
 }
@@ -54,7 +59,8 @@ fn build_load_from_address_instruction_from_constant_short(destination_name: Reg
     return optional_boxed!(
         UnaryByteOp::new_no_op(
             boxed!(AddressedByShortSource::new_from_constant()),
-            boxed!(RegisterDestination::new(destination_name))
+            boxed!(RegisterDestination::new(destination_name)),
+            4
         )
     );
 }
@@ -68,6 +74,7 @@ pub fn load_instruction(instruction_byte: u8) -> Option<Box<dyn Instruction>> {
         0x43 => build_load_instruction(RegisterName::E, RegisterName::B),
         0x44 => build_load_instruction(RegisterName::H, RegisterName::B),
         0x45 => build_load_instruction(RegisterName::L, RegisterName::B),
+        0x46 => build_load_from_address_instruction(DoubleRegisterName::HL, RegisterName::B),
         0x47 => build_load_instruction(RegisterName::A, RegisterName::B),
         0x06 => build_load_instruction_from_constant_byte(RegisterName::B),
         // Load into Register C
@@ -77,6 +84,7 @@ pub fn load_instruction(instruction_byte: u8) -> Option<Box<dyn Instruction>> {
         0x4B => build_load_instruction(RegisterName::E, RegisterName::C),
         0x4C => build_load_instruction(RegisterName::H, RegisterName::C),
         0x4D => build_load_instruction(RegisterName::L, RegisterName::C),
+        0x4E => build_load_from_address_instruction(DoubleRegisterName::HL, RegisterName::C),
         0x4F => build_load_instruction(RegisterName::A, RegisterName::C),
         0x0E => build_load_instruction_from_constant_byte(RegisterName::C),
         // Load into Register D
@@ -86,6 +94,7 @@ pub fn load_instruction(instruction_byte: u8) -> Option<Box<dyn Instruction>> {
         0x53 => build_load_instruction(RegisterName::E, RegisterName::D),
         0x54 => build_load_instruction(RegisterName::H, RegisterName::D),
         0x55 => build_load_instruction(RegisterName::L, RegisterName::D),
+        0x56 => build_load_from_address_instruction(DoubleRegisterName::HL, RegisterName::D),
         0x57 => build_load_instruction(RegisterName::A, RegisterName::D),
         0x16 => build_load_instruction_from_constant_byte(RegisterName::D),
         // Load into Register E
@@ -95,6 +104,7 @@ pub fn load_instruction(instruction_byte: u8) -> Option<Box<dyn Instruction>> {
         0x5B => build_load_instruction(RegisterName::E, RegisterName::E),
         0x5C => build_load_instruction(RegisterName::H, RegisterName::E),
         0x5D => build_load_instruction(RegisterName::L, RegisterName::E),
+        0x5E => build_load_from_address_instruction(DoubleRegisterName::HL, RegisterName::E),
         0x5F => build_load_instruction(RegisterName::A, RegisterName::E),
         0x1E => build_load_instruction_from_constant_byte(RegisterName::E),
         // Load into Register H
@@ -104,6 +114,7 @@ pub fn load_instruction(instruction_byte: u8) -> Option<Box<dyn Instruction>> {
         0x63 => build_load_instruction(RegisterName::E, RegisterName::H),
         0x64 => build_load_instruction(RegisterName::H, RegisterName::H),
         0x65 => build_load_instruction(RegisterName::L, RegisterName::H),
+        0x66 => build_load_from_address_instruction(DoubleRegisterName::HL, RegisterName::H),
         0x67 => build_load_instruction(RegisterName::A, RegisterName::H),
         0x26 => build_load_instruction_from_constant_byte(RegisterName::H),
         // Load into Register L
@@ -113,6 +124,7 @@ pub fn load_instruction(instruction_byte: u8) -> Option<Box<dyn Instruction>> {
         0x6B => build_load_instruction(RegisterName::E, RegisterName::L),
         0x6C => build_load_instruction(RegisterName::H, RegisterName::L),
         0x6D => build_load_instruction(RegisterName::L, RegisterName::L),
+        0x6E => build_load_from_address_instruction(DoubleRegisterName::HL, RegisterName::L),
         0x6F => build_load_instruction(RegisterName::A, RegisterName::L),
         0x2E => build_load_instruction_from_constant_byte(RegisterName::L),
         // Load into Register A
@@ -122,6 +134,7 @@ pub fn load_instruction(instruction_byte: u8) -> Option<Box<dyn Instruction>> {
         0x7B => build_load_instruction(RegisterName::E, RegisterName::A),
         0x7C => build_load_instruction(RegisterName::H, RegisterName::A),
         0x7D => build_load_instruction(RegisterName::L, RegisterName::A),
+        0x7E => build_load_from_address_instruction(DoubleRegisterName::HL, RegisterName::A),
         0x7F => build_load_instruction(RegisterName::A, RegisterName::A),
         0x3E => build_load_instruction_from_constant_byte(RegisterName::A),
         // Load into Addressed by HL
@@ -134,10 +147,13 @@ pub fn load_instruction(instruction_byte: u8) -> Option<Box<dyn Instruction>> {
         0x77 => build_load_into_address_instruction(RegisterName::A, DoubleRegisterName::HL),
         0x36 => build_load_into_address_instruction_from_constant_byte(DoubleRegisterName::HL),
         // Load Short Fields
-        0x01 => optional_boxed!(UnaryShortOp::new_no_op(boxed!(ConstantShortSource::new()), boxed!(DoubleRegisterDestination::new(DoubleRegisterName::BC)))),
-        0x11 => optional_boxed!(UnaryShortOp::new_no_op(boxed!(ConstantShortSource::new()), boxed!(DoubleRegisterDestination::new(DoubleRegisterName::DE)))),
-        0x21 => optional_boxed!(UnaryShortOp::new_no_op(boxed!(ConstantShortSource::new()), boxed!(DoubleRegisterDestination::new(DoubleRegisterName::HL)))),
-        0x31 => optional_boxed!(UnaryShortOp::new_no_op(boxed!(ConstantShortSource::new()), boxed!(StackPointerDestination::new()))),
+        0x01 => optional_boxed!(UnaryShortOp::new_no_op(boxed!(ConstantShortSource::new()), boxed!(DoubleRegisterDestination::new(DoubleRegisterName::BC)), 3)),
+        0x11 => optional_boxed!(UnaryShortOp::new_no_op(boxed!(ConstantShortSource::new()), boxed!(DoubleRegisterDestination::new(DoubleRegisterName::DE)), 3)),
+        0x21 => optional_boxed!(UnaryShortOp::new_no_op(boxed!(ConstantShortSource::new()), boxed!(DoubleRegisterDestination::new(DoubleRegisterName::HL)), 3)),
+        0x31 => optional_boxed!(UnaryShortOp::new_no_op(boxed!(ConstantShortSource::new()), boxed!(StackPointerDestination::new()), 3)),
+        // Load Stack Pointer
+        0xF9 => optional_boxed!(UnaryShortOp::new_no_op(boxed!(DoubleRegisterSource::new(DoubleRegisterName::HL)), boxed!(StackPointerDestination::new()), 2)),
+        0x08 => optional_boxed!(UnaryShortOp::new_no_op(boxed!(StackPointerSource::new()), boxed!(ShortAddressedByShortDestination::new_from_constant()), 5)),
         // Load Addressed by Double Register Fields
         0x02 => build_load_into_address_instruction(RegisterName::A, DoubleRegisterName::BC),
         0x12 => build_load_into_address_instruction(RegisterName::A, DoubleRegisterName::DE),
@@ -145,38 +161,44 @@ pub fn load_instruction(instruction_byte: u8) -> Option<Box<dyn Instruction>> {
         0x22 => optional_boxed!(
                     UnaryByteOp::new_no_op(
                         boxed!((RegisterSource::new(RegisterName::A))),
-                        boxed!(AddressedByShortDestination::new_from_register_then_increment(DoubleRegisterName::HL))
+                        boxed!(AddressedByShortDestination::new_from_register_then_increment(DoubleRegisterName::HL)),
+                        2
                     )
                 ),
         0x32 => optional_boxed!(
                     UnaryByteOp::new_no_op(
                         boxed!((RegisterSource::new(RegisterName::A))),
-                        boxed!(AddressedByShortDestination::new_from_register_then_decrement(DoubleRegisterName::HL))
+                        boxed!(AddressedByShortDestination::new_from_register_then_decrement(DoubleRegisterName::HL)),
+                        2
                     )
                 ),
         // Load Addressed by Byte Fields
         0xE0 => optional_boxed!(
                     UnaryByteOp::new_no_op(
                         boxed!((RegisterSource::new(RegisterName::A))),
-                        boxed!(AddressedByByteDestination::new(boxed!(ConstantByteSource::new())))
+                        boxed!(AddressedByByteDestination::new(boxed!(ConstantByteSource::new()))),
+                        3
                     )
                 ),
         0xE2 => optional_boxed!(
                     UnaryByteOp::new_no_op(
                         boxed!((RegisterSource::new(RegisterName::A))),
-                        boxed!(AddressedByByteDestination::new(boxed!(RegisterSource::new(RegisterName::C))))
+                        boxed!(AddressedByByteDestination::new(boxed!(RegisterSource::new(RegisterName::C)))),
+                        2
                     )
                 ),
         0xF0 => optional_boxed!(
                     UnaryByteOp::new_no_op(
                         boxed!(AddressedByByteSource::new(boxed!(ConstantByteSource::new()))),
-                        boxed!((RegisterDestination::new(RegisterName::A)))
+                        boxed!((RegisterDestination::new(RegisterName::A))),
+                        3
                     )
                 ),
         0xF2 => optional_boxed!(
                     UnaryByteOp::new_no_op(
                         boxed!(AddressedByByteSource::new(boxed!(RegisterSource::new(RegisterName::C)))),
-                        boxed!((RegisterDestination::new(RegisterName::A)))
+                        boxed!((RegisterDestination::new(RegisterName::A))),
+                        2
                     )
                 ),
         // Load from Addressed by Short Fields
@@ -185,19 +207,22 @@ pub fn load_instruction(instruction_byte: u8) -> Option<Box<dyn Instruction>> {
         0x2A => optional_boxed!(
                     UnaryByteOp::new_no_op(
                         boxed!(AddressedByShortSource::new_from_register_then_increment(DoubleRegisterName::HL)),
-                        boxed!(RegisterDestination::new(RegisterName::A))
+                        boxed!(RegisterDestination::new(RegisterName::A)),
+                        2
                     )
                 ),
         0x3A => optional_boxed!(
                     UnaryByteOp::new_no_op(
                         boxed!(AddressedByShortSource::new_from_register_then_decrement(DoubleRegisterName::HL)),
-                        boxed!(RegisterDestination::new(RegisterName::A))
+                        boxed!(RegisterDestination::new(RegisterName::A)),
+                        2
                     )
                 ),
         0xEA => optional_boxed!(
                     UnaryByteOp::new_no_op(
                         boxed!(RegisterSource::new(RegisterName::A)),
-                        boxed!(AddressedByShortDestination::new_from_constant())
+                        boxed!(AddressedByShortDestination::new_from_constant()),
+                        4
                     )
                 ),
         0xFA => build_load_from_address_instruction_from_constant_short(RegisterName::A),
@@ -1,4 +1,5 @@
 pub mod instruction;
+pub mod instruction_table;
 pub mod instructions;
 
 mod common;
@@ -6,13 +7,17 @@ mod destinations;
 mod sources;
 mod utils;
 
+mod accumulator;
 mod add;
+mod and;
 mod bit;
 mod compare;
+mod control;
 mod dec;
 mod inc;
 mod jump;
 mod load;
+mod or;
 mod rotate;
 mod stack;
 mod subtract;
@@ -0,0 +1,3 @@
+pub mod instructions;
+
+mod or;
@@ -6,6 +6,10 @@ use super::rotate_a_right::rotate_a_right;
 use super::rotate_a_right_through_carry_flag::rotate_a_right_through_carry_flag;
 use super::rotate_right::rotate_right;
 use super::rotate_right_through_carry_flag::rotate_right_through_carry_flag;
+use super::shift_left_arithmetic::shift_left_arithmetic;
+use super::shift_right_arithmetic::shift_right_arithmetic;
+use super::shift_right_logical::shift_right_logical;
+use super::swap::swap;
 use super::super::common::{UnaryByteOp, UnaryByteOpFn};
 use super::super::sources::{AddressedByShortSource, RegisterSource};
 use super::super::destinations::{AddressedByShortDestination, RegisterDestination};
@@ -13,12 +17,23 @@ use super::super::instruction::Instruction;
 use super::super::super::registers::{DoubleRegisterName, RegisterName};
 use crate::{boxed, optional_boxed};
 
+fn build_rotate_a_instruction(operation: UnaryByteOpFn) -> Option<Box<dyn Instruction>> {
+    return optional_boxed!(
+        UnaryByteOp::new(
+            boxed!(RegisterSource::new(RegisterName::A)),
+            operation,
+            boxed!(RegisterDestination::new(RegisterName::A)),
+            1)
+    );
+}
+
 fn build_rotate_register_instruction(register: RegisterName, operation: UnaryByteOpFn) -> Option<Box<dyn Instruction>> {
     return optional_boxed!(
         UnaryByteOp::new(
             boxed!(RegisterSource::new(register)),
             operation,
-            boxed!(RegisterDestination::new(register)))
+            boxed!(RegisterDestination::new(register)),
+            2)
     );
 }
 
@@ -27,18 +42,19 @@ fn build_rotate_memory_instruction(register: DoubleRegisterName, operation: Unar
         UnaryByteOp::new(
             boxed!(AddressedByShortSource::new_from_register(register)),
             operation,
-            boxed!(AddressedByShortDestination::new_from_register(register)))
+            boxed!(AddressedByShortDestination::new_from_register(register)),
+            4)
     );
 }
 
 pub fn load_instruction(instruction_byte: u8) -> Option<Box<dyn Instruction>> {
     return match instruction_byte {
         // Rotate A Left
-        0x07 => build_rotate_register_instruction(RegisterName::A, rotate_a_left),
-        0x17 => build_rotate_register_instruction(RegisterName::A, rotate_a_left_through_carry_flag),
+        0x07 => build_rotate_a_instruction(rotate_a_left),
+        0x17 => build_rotate_a_instruction(rotate_a_left_through_carry_flag),
         // Rotate A Right
-        0x0F => build_rotate_register_instruction(RegisterName::A, rotate_a_right),
-        0x1F => build_rotate_register_instruction(RegisterName::A, rotate_a_right_through_carry_flag),
+        0x0F => build_rotate_a_instruction(rotate_a_right),
+        0x1F => build_rotate_a_instruction(rotate_a_right_through_carry_flag),
         _ => None,
     };
 }
@@ -85,6 +101,46 @@ pub fn load_prefix_instruction(instruction_byte: u8) -> Option<Box<dyn Instructi
         0x1E => build_rotate_memory_instruction(DoubleRegisterName::HL, rotate_right_through_carry_flag),
         0x1F => build_rotate_register_instruction(RegisterName::A, rotate_right_through_carry_flag),
         
+        // Shift Left Arithmetic
+        0x20 => build_rotate_register_instruction(RegisterName::B, shift_left_arithmetic),
+        0x21 => build_rotate_register_instruction(RegisterName::C, shift_left_arithmetic),
+        0x22 => build_rotate_register_instruction(RegisterName::D, shift_left_arithmetic),
+        0x23 => build_rotate_register_instruction(RegisterName::E, shift_left_arithmetic),
+        0x24 => build_rotate_register_instruction(RegisterName::H, shift_left_arithmetic),
+        0x25 => build_rotate_register_instruction(RegisterName::L, shift_left_arithmetic),
+        0x26 => build_rotate_memory_instruction(DoubleRegisterName::HL, shift_left_arithmetic),
+        0x27 => build_rotate_register_instruction(RegisterName::A, shift_left_arithmetic),
+        
+        // Shift Right Arithmetic
+        0x28 => build_rotate_register_instruction(RegisterName::B, shift_right_arithmetic),
+        0x29 => build_rotate_register_instruction(RegisterName::C, shift_right_arithmetic),
+        0x2A => build_rotate_register_instruction(RegisterName::D, shift_right_arithmetic),
+        0x2B => build_rotate_register_instruction(RegisterName::E, shift_right_arithmetic),
+        0x2C => build_rotate_register_instruction(RegisterName::H, shift_right_arithmetic),
+        0x2D => build_rotate_register_instruction(RegisterName::L, shift_right_arithmetic),
+        0x2E => build_rotate_memory_instruction(DoubleRegisterName::HL, shift_right_arithmetic),
+        0x2F => build_rotate_register_instruction(RegisterName::A, shift_right_arithmetic),
+        
+        // Swap Nibbles
+        0x30 => build_rotate_register_instruction(RegisterName::B, swap),
+        0x31 => build_rotate_register_instruction(RegisterName::C, swap),
+        0x32 => build_rotate_register_instruction(RegisterName::D, swap),
+        0x33 => build_rotate_register_instruction(RegisterName::E, swap),
+        0x34 => build_rotate_register_instruction(RegisterName::H, swap),
+        0x35 => build_rotate_register_instruction(RegisterName::L, swap),
+        0x36 => build_rotate_memory_instruction(DoubleRegisterName::HL, swap),
+        0x37 => build_rotate_register_instruction(RegisterName::A, swap),
+        
+        // Shift Right Logical
+        0x38 => build_rotate_register_instruction(RegisterName::B, shift_right_logical),
+        0x39 => build_rotate_register_instruction(RegisterName::C, shift_right_logical),
+        0x3A => build_rotate_register_instruction(RegisterName::D, shift_right_logical),
+        0x3B => build_rotate_register_instruction(RegisterName::E, shift_right_logical),
+        0x3C => build_rotate_register_instruction(RegisterName::H, shift_right_logical),
+        0x3D => build_rotate_register_instruction(RegisterName::L, shift_right_logical),
+        0x3E => build_rotate_memory_instruction(DoubleRegisterName::HL, shift_right_logical),
+        0x3F => build_rotate_register_instruction(RegisterName::A, shift_right_logical),
+        
         _ => None,
     };
 }
@@ -7,3 +7,7 @@ mod rotate_a_right;
 mod rotate_a_right_through_carry_flag;
 mod rotate_right;
 mod rotate_right_through_carry_flag;
+mod shift_left_arithmetic;
+mod shift_right_arithmetic;
+mod shift_right_logical;
+mod swap;
@@ -0,0 +1,67 @@
+use super::super::super::InstructionContext;
+
+pub fn shift_right_arithmetic(context: &mut InstructionContext, value: u8) -> u8 {
+    let new_value = (value >> 1) | (value & 0x80);
+    
+    context.registers_mut().carry_flag.set((value & 0x1) > 0);
+    context.registers_mut().zero_flag.set(new_value == 0);
+    context.registers_mut().half_carry_flag.reset();
+    context.registers_mut().subtract_flag.reset();
+    
+    return new_value;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpu::testing::build_test_instruction_context;
+    use crate::{as_hex};
+    
+    #[test]
+    fn test_run_low_bit_on_sets_new_value_and_carry_flag() {
+        const INITIAL_VALUE: u8 = 0x81;
+        const EXPECTED_VALUE: u8 = 0xC0;
+        let mut context = build_test_instruction_context();
+        context.registers_mut().carry_flag.reset();
+        
+        let result = shift_right_arithmetic(&mut context, INITIAL_VALUE);
+        
+        assert_eq!(as_hex!(result), as_hex!(EXPECTED_VALUE));
+        assert_eq!(context.registers_mut().carry_flag.get(), true);
+    }
+    
+    #[test]
+    fn test_run_high_bit_kept_sets_new_value_and_carry_flag() {
+        const INITIAL_VALUE: u8 = 0x82;
+        const EXPECTED_VALUE: u8 = 0xC1;
+        let mut context = build_test_instruction_context();
+        context.registers_mut().carry_flag.activate();
+        
+        let result = shift_right_arithmetic(&mut context, INITIAL_VALUE);
+        
+        assert_eq!(as_hex!(result), as_hex!(EXPECTED_VALUE));
+        assert_eq!(context.registers_mut().carry_flag.get(), false);
+    }
+    
+    #[test]
+    fn test_run_zero_activates_zero_flag() {
+        const INITIAL_VALUE: u8 = 0x01;
+        let mut context = build_test_instruction_context();
+        context.registers_mut().zero_flag.reset();
+        
+        shift_right_arithmetic(&mut context, INITIAL_VALUE);
+        
+        assert_eq!(context.registers_mut().zero_flag.get(), true);
+    }
+    
+    #[test]
+    fn test_run_non_zero_resets_zero_flag() {
+        const INITIAL_VALUE: u8 = 0x12;
+        let mut context = build_test_instruction_context();
+        context.registers_mut().zero_flag.activate();
+        
+        shift_right_arithmetic(&mut context, INITIAL_VALUE);
+        
+        assert_eq!(context.registers_mut().zero_flag.get(), false);
+    }
+}
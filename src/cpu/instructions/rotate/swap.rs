@@ -0,0 +1,63 @@
+use super::super::super::InstructionContext;
+
+pub fn swap(context: &mut InstructionContext, value: u8) -> u8 {
+    let new_value = (value << 4) | (value >> 4);
+    
+    context.registers_mut().carry_flag.reset();
+    context.registers_mut().zero_flag.set(new_value == 0);
+    context.registers_mut().half_carry_flag.reset();
+    context.registers_mut().subtract_flag.reset();
+    
+    return new_value;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpu::testing::build_test_instruction_context;
+    use crate::{as_hex};
+    
+    #[test]
+    fn test_run_swaps_nibbles() {
+        const INITIAL_VALUE: u8 = 0x12;
+        const EXPECTED_VALUE: u8 = 0x21;
+        let mut context = build_test_instruction_context();
+        
+        let result = swap(&mut context, INITIAL_VALUE);
+        
+        assert_eq!(as_hex!(result), as_hex!(EXPECTED_VALUE));
+    }
+    
+    #[test]
+    fn test_run_resets_carry_flag() {
+        const INITIAL_VALUE: u8 = 0xF0;
+        let mut context = build_test_instruction_context();
+        context.registers_mut().carry_flag.activate();
+        
+        swap(&mut context, INITIAL_VALUE);
+        
+        assert_eq!(context.registers_mut().carry_flag.get(), false);
+    }
+    
+    #[test]
+    fn test_run_zero_activates_zero_flag() {
+        const INITIAL_VALUE: u8 = 0x00;
+        let mut context = build_test_instruction_context();
+        context.registers_mut().zero_flag.reset();
+        
+        swap(&mut context, INITIAL_VALUE);
+        
+        assert_eq!(context.registers_mut().zero_flag.get(), true);
+    }
+    
+    #[test]
+    fn test_run_non_zero_resets_zero_flag() {
+        const INITIAL_VALUE: u8 = 0x12;
+        let mut context = build_test_instruction_context();
+        context.registers_mut().zero_flag.activate();
+        
+        swap(&mut context, INITIAL_VALUE);
+        
+        assert_eq!(context.registers_mut().zero_flag.get(), false);
+    }
+}
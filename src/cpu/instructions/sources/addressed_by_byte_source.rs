@@ -31,7 +31,7 @@ mod tests {
     #[test]
     fn test_read_reads_memory() {
         const EXPECTED_VALUE: u8 = 0xAB;
-        const SUBADDRESS_VALUE: u8 = 0x78;
+        const SUBADDRESS_VALUE: u8 = 0x88;
         const EXPECTED_ADDRESS: u16 = build_u16!(0xFF, SUBADDRESS_VALUE);
         let mut context = build_test_instruction_context();
         context.registers_mut().a.set(SUBADDRESS_VALUE);
@@ -42,4 +42,16 @@ mod tests {
         
         assert_eq!(as_hex!(result), as_hex!(EXPECTED_VALUE));
     }
+    
+    #[test]
+    fn test_read_unmapped_io_register_reads_open_bus_value() {
+        const SUBADDRESS_VALUE: u8 = 0x44;
+        let mut context = build_test_instruction_context();
+        context.registers_mut().a.set(SUBADDRESS_VALUE);
+        let source = AddressedByByteSource::new(boxed!(RegisterSource::new(RegisterName::A)));
+        
+        let result = source.read(&mut context);
+        
+        assert_eq!(as_hex!(result), as_hex!(0xFF));
+    }
 }
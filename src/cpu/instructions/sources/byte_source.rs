@@ -1,5 +1,5 @@
 use super::super::super::InstructionContext;
 
-pub trait ByteSource {
+pub trait ByteSource: Send + Sync {
     fn read(&self, context: &mut InstructionContext) -> u8;
 }
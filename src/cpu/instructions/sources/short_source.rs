@@ -1,5 +1,5 @@
 use super::super::super::InstructionContext;
 
-pub trait ShortSource {
+pub trait ShortSource: Send + Sync {
     fn read(&self, context: &mut InstructionContext) -> u16;
 }
@@ -2,6 +2,9 @@ use super::super::instruction::Instruction;
 use super::super::jump::{JumpConditionFn, jump_with_extra_work};
 use super::super::super::InstructionContext;
 
+const TAKEN_CYCLES: u8 = 6;
+const NOT_TAKEN_CYCLES: u8 = 3;
+
 pub struct CallInstruction {
     condition: JumpConditionFn,
 }
@@ -13,14 +16,15 @@ impl CallInstruction {
 }
 
 impl Instruction for CallInstruction {
-	fn run(&self, context: &mut InstructionContext) {
+	fn run(&self, context: &mut InstructionContext) -> u8 {
         let new_counter = context.program_mut().read_next_short();
         let current_counter = context.program_mut().get_counter();
         
-        jump_with_extra_work(self.condition, context, |context_again| {
+        let jumped = jump_with_extra_work(self.condition, context, |context_again| {
             context_again.stack_mut().push(current_counter);
             return new_counter;
         });
+        return if jumped { TAKEN_CYCLES } else { NOT_TAKEN_CYCLES };
 	}
 }
 
@@ -37,7 +41,7 @@ mod tests {
     
     #[test]
     fn test_run_condition_true_jumps_to_new_address() {
-        const INITIAL_COUNTER: u16 = 0x0A;
+        const INITIAL_COUNTER: u16 = 0xC00A;
         const COUNTER_TO_JUMP_TO: u16 = 0x07;
         let mut context = build_test_instruction_context();
         context.stack_mut().set_pointer(0xFFFE);
@@ -45,9 +49,10 @@ mod tests {
         context.memory_mut().write_short(INITIAL_COUNTER, COUNTER_TO_JUMP_TO);
         
         let instruction = CallInstruction::new(always);
-        instruction.run(&mut context);
+        let cycles = instruction.run(&mut context);
         
         assert_eq!(context.program().get_counter(), COUNTER_TO_JUMP_TO);
+        assert_eq!(cycles, TAKEN_CYCLES);
     }
     
     #[test]
@@ -61,9 +66,10 @@ mod tests {
         context.memory_mut().write_short(INITIAL_COUNTER, COUNTER_TO_JUMP_TO);
         
         let instruction = CallInstruction::new(invalid_condition);
-        instruction.run(&mut context);
+        let cycles = instruction.run(&mut context);
         
         assert_eq!(context.program().get_counter(), COUNTER_AFTER_READING_NEW_ADDRESS);
+        assert_eq!(cycles, NOT_TAKEN_CYCLES);
     }
     
     #[test]
@@ -2,7 +2,9 @@ use super::super::jump::{JumpConditionFn, always, is_carry_flag_off, is_carry_fl
 use super::call_instruction::CallInstruction;
 use super::pop_instruction::PopInstruction;
 use super::push_instruction::PushInstruction;
+use super::restart_instruction::RestartInstruction;
 use super::return_instruction::ReturnInstruction;
+use super::return_from_interrupt_instruction::ReturnFromInterruptInstruction;
 use super::super::instruction::Instruction;
 use super::super::sources::{ByteSource, ConstantByteSource};
 use super::super::super::registers::DoubleRegisterName;
@@ -35,7 +37,16 @@ fn build_push_instruction(register: DoubleRegisterName) -> Option<Box<dyn Instru
 fn build_return_instruction(condition: JumpConditionFn) -> Option<Box<dyn Instruction>> {
     return optional_boxed!(
         ReturnInstruction::new(
-            condition
+            condition,
+            5
+        )
+    );
+}
+
+fn build_restart_instruction(address: u16) -> Option<Box<dyn Instruction>> {
+    return optional_boxed!(
+        RestartInstruction::new(
+            address
         )
     );
 }
@@ -59,11 +70,21 @@ pub fn load_instruction(instruction_byte: u8) -> Option<Box<dyn Instruction>> {
         0xE5 => build_push_instruction(DoubleRegisterName::HL),
         0xF5 => build_push_instruction(DoubleRegisterName::AF),
         // Return Instructions
-        0xC9 => build_return_instruction(always),
+        0xC9 => optional_boxed!(ReturnInstruction::new(always, 4)),
         0xC0 => build_return_instruction(is_zero_flag_off),
         0xC8 => build_return_instruction(is_zero_flag_on),
         0xD0 => build_return_instruction(is_carry_flag_off),
         0xD8 => build_return_instruction(is_carry_flag_on),
+        0xD9 => optional_boxed!(ReturnFromInterruptInstruction::new()),
+        // Restart Instructions
+        0xC7 => build_restart_instruction(0x00),
+        0xCF => build_restart_instruction(0x08),
+        0xD7 => build_restart_instruction(0x10),
+        0xDF => build_restart_instruction(0x18),
+        0xE7 => build_restart_instruction(0x20),
+        0xEF => build_restart_instruction(0x28),
+        0xF7 => build_restart_instruction(0x30),
+        0xFF => build_restart_instruction(0x38),
         _ => None,
     };
 }
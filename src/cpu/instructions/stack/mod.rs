@@ -1,5 +1,7 @@
 mod call_instruction;
 mod pop_instruction;
 mod push_instruction;
+mod restart_instruction;
+mod return_from_interrupt_instruction;
 mod return_instruction;
 pub mod instructions;
@@ -5,6 +5,8 @@ use super::super::super::registers::DoubleRegisterName;
 use super::super::super::InstructionContext;
 use crate::{boxed};
 
+const CYCLES: u8 = 3;
+
 pub struct PopInstruction {
     destination: Box<dyn ShortDestination>,
 }
@@ -20,9 +22,10 @@ impl PopInstruction {
 }
 
 impl Instruction for PopInstruction {
-	fn run(&self, context: &mut InstructionContext) {
+	fn run(&self, context: &mut InstructionContext) -> u8 {
         let value = context.stack_mut().pop();
-        self.destination.assign(context, value)
+        self.destination.assign(context, value);
+        return CYCLES;
 	}
 }
 
@@ -5,6 +5,8 @@ use super::super::super::registers::DoubleRegisterName;
 use super::super::super::InstructionContext;
 use crate::{boxed};
 
+const CYCLES: u8 = 4;
+
 pub struct PushInstruction {
     source: Box<dyn ShortSource>,
 }
@@ -20,9 +22,10 @@ impl PushInstruction {
 }
 
 impl Instruction for PushInstruction {
-	fn run(&self, context: &mut InstructionContext) {
+	fn run(&self, context: &mut InstructionContext) -> u8 {
         let value = self.source.read(context);
         context.stack_mut().push(value);
+        return CYCLES;
 	}
 }
 
@@ -0,0 +1,62 @@
+use super::super::instruction::Instruction;
+use super::super::jump::{always, jump_with_extra_work};
+use super::super::super::InstructionContext;
+
+const CYCLES: u8 = 4;
+
+pub struct RestartInstruction {
+    address: u16,
+}
+
+impl RestartInstruction {
+	pub fn new(address: u16) -> RestartInstruction {
+		return RestartInstruction {address: address};
+	}
+}
+
+impl Instruction for RestartInstruction {
+	fn run(&self, context: &mut InstructionContext) -> u8 {
+        let new_counter = self.address;
+        let current_counter = context.program_mut().get_counter();
+        
+        jump_with_extra_work(always, context, |context_again| {
+            context_again.stack_mut().push(current_counter);
+            return new_counter;
+        });
+        return CYCLES;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpu::testing::build_test_instruction_context;
+    
+    #[test]
+    fn test_run_jumps_to_restart_address() {
+        const INITIAL_COUNTER: u16 = 0x1234;
+        const RESTART_ADDRESS: u16 = 0x0038;
+        let mut context = build_test_instruction_context();
+        context.stack_mut().set_pointer(0xFFFE);
+        context.program_mut().set_counter(INITIAL_COUNTER);
+        
+        let instruction = RestartInstruction::new(RESTART_ADDRESS);
+        instruction.run(&mut context);
+        
+        assert_eq!(context.program().get_counter(), RESTART_ADDRESS);
+    }
+    
+    #[test]
+    fn test_run_stores_old_next_program_address_on_stack() {
+        const INITIAL_COUNTER: u16 = 0x1234;
+        const RESTART_ADDRESS: u16 = 0x0038;
+        let mut context = build_test_instruction_context();
+        context.stack_mut().set_pointer(0xFFFE);
+        context.program_mut().set_counter(INITIAL_COUNTER);
+        
+        let instruction = RestartInstruction::new(RESTART_ADDRESS);
+        instruction.run(&mut context);
+        
+        assert_eq!(context.stack_mut().pop(), INITIAL_COUNTER);
+    }
+}
@@ -0,0 +1,58 @@
+use super::super::instruction::Instruction;
+use super::super::jump::{always, jump_with_extra_work};
+use super::super::super::InstructionContext;
+
+const CYCLES: u8 = 4;
+
+pub struct ReturnFromInterruptInstruction {
+}
+
+impl ReturnFromInterruptInstruction {
+	pub fn new() -> ReturnFromInterruptInstruction {
+		return ReturnFromInterruptInstruction {};
+	}
+}
+
+impl Instruction for ReturnFromInterruptInstruction {
+	fn run(&self, context: &mut InstructionContext) -> u8 {
+        jump_with_extra_work(always, context, |context_again| {
+            return context_again.stack_mut().pop();
+        });
+        context.state_mut().enable_interrupts();
+        return CYCLES;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpu::testing::build_test_instruction_context;
+    
+    #[test]
+    fn test_run_jumps_to_address_on_stack() {
+        const INITIAL_COUNTER: u16 = 0x0A;
+        const COUNTER_TO_JUMP_TO: u16 = 0x1234;
+        let mut context = build_test_instruction_context();
+        context.stack_mut().set_pointer(0xFFFE);
+        context.stack_mut().push(COUNTER_TO_JUMP_TO);
+        context.program_mut().set_counter(INITIAL_COUNTER);
+        
+        let instruction = ReturnFromInterruptInstruction::new();
+        instruction.run(&mut context);
+        
+        assert_eq!(context.program().get_counter(), COUNTER_TO_JUMP_TO);
+    }
+    
+    #[test]
+    fn test_run_enables_interrupts_immediately() {
+        let mut context = build_test_instruction_context();
+        context.stack_mut().set_pointer(0xFFFE);
+        context.stack_mut().push(0x1234);
+        context.state_mut().disable_interrupts();
+        
+        let instruction = ReturnFromInterruptInstruction::new();
+        instruction.run(&mut context);
+        
+        assert_eq!(context.state().are_interrupts_enabled(), true);
+    }
+}
@@ -2,21 +2,25 @@ use super::super::instruction::Instruction;
 use super::super::jump::{JumpConditionFn, jump_with_extra_work};
 use super::super::super::InstructionContext;
 
+const NOT_TAKEN_CYCLES: u8 = 2;
+
 pub struct ReturnInstruction {
     condition: JumpConditionFn,
+    taken_cycles: u8,
 }
 
 impl ReturnInstruction {
-	pub fn new(condition: JumpConditionFn) -> ReturnInstruction {
-		return ReturnInstruction {condition: condition};
+	pub fn new(condition: JumpConditionFn, taken_cycles: u8) -> ReturnInstruction {
+		return ReturnInstruction {condition: condition, taken_cycles: taken_cycles};
 	}
 }
 
 impl Instruction for ReturnInstruction {
-	fn run(&self, context: &mut InstructionContext) {
-        jump_with_extra_work(self.condition, context, |context_again| {
+	fn run(&self, context: &mut InstructionContext) -> u8 {
+        let jumped = jump_with_extra_work(self.condition, context, |context_again| {
             return context_again.stack_mut().pop();
         });
+        return if jumped { self.taken_cycles } else { NOT_TAKEN_CYCLES };
 	}
 }
 
@@ -28,6 +32,8 @@ mod tests {
     use crate::cpu::testing::build_test_instruction_context;
     use crate::{as_hex, boxed};
     
+    const TAKEN_CYCLES: u8 = 5;
+    
     fn invalid_condition(context: &InstructionContext) -> bool {
         return false;
     }
@@ -41,10 +47,11 @@ mod tests {
         context.stack_mut().push(COUNTER_TO_JUMP_TO);
         context.program_mut().set_counter(INITIAL_COUNTER);
         
-        let instruction = ReturnInstruction::new(always);
-        instruction.run(&mut context);
+        let instruction = ReturnInstruction::new(always, TAKEN_CYCLES);
+        let cycles = instruction.run(&mut context);
         
         assert_eq!(context.program().get_counter(), COUNTER_TO_JUMP_TO);
+        assert_eq!(cycles, TAKEN_CYCLES);
     }
     
     #[test]
@@ -56,10 +63,11 @@ mod tests {
         context.stack_mut().push(COUNTER_TO_JUMP_TO);
         context.program_mut().set_counter(INITIAL_COUNTER);
         
-        let instruction = ReturnInstruction::new(invalid_condition);
-        instruction.run(&mut context);
+        let instruction = ReturnInstruction::new(invalid_condition, TAKEN_CYCLES);
+        let cycles = instruction.run(&mut context);
         
         assert_eq!(context.program().get_counter(), INITIAL_COUNTER);
+        assert_eq!(cycles, NOT_TAKEN_CYCLES);
     }
     
     #[test]
@@ -73,7 +81,7 @@ mod tests {
         context.stack_mut().push(COUNTER_TO_JUMP_TO);
         context.program_mut().set_counter(INITIAL_COUNTER);
         
-        let instruction = ReturnInstruction::new(always);
+        let instruction = ReturnInstruction::new(always, TAKEN_CYCLES);
         instruction.run(&mut context);
         
         assert_eq!(context.stack_mut().get_pointer(), INITIAL_STACK_POINTER);
@@ -89,7 +97,7 @@ mod tests {
         context.program_mut().set_counter(INITIAL_COUNTER);
         let stack_pointer_before = context.stack().get_pointer();
         
-        let instruction = ReturnInstruction::new(invalid_condition);
+        let instruction = ReturnInstruction::new(invalid_condition, TAKEN_CYCLES);
         instruction.run(&mut context);
         
         assert_eq!(context.stack().get_pointer(), stack_pointer_before);
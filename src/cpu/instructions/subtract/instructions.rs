@@ -1,29 +1,45 @@
 use super::subtract::subtract;
+use super::subtract_with_carry::subtract_with_carry;
 use super::super::instruction::Instruction;
-use super::super::common::binary_byte_op::BinaryByteOp;
-use super::super::sources::constant_byte_source::ConstantByteSource;
-use super::super::sources::register_source::RegisterSource;
-use super::super::super::registers::register_names::RegisterName;
+use super::super::common::{BinaryByteOp, BinaryByteOpFn};
+use super::super::sources::{AddressedByShortSource, ConstantByteSource, RegisterSource};
+use super::super::super::registers::{DoubleRegisterName, RegisterName};
 use crate::{boxed, optional_boxed};
 
-fn build_subtract_instruction(other_source_name: RegisterName) -> Option<Box<dyn Instruction>> {
-    return optional_boxed!(BinaryByteOp::new_inplace_a_op(boxed!(RegisterSource::new(other_source_name)), subtract));
+fn build_subtract_instruction(other_source_name: RegisterName, operation: BinaryByteOpFn) -> Option<Box<dyn Instruction>> {
+    return optional_boxed!(BinaryByteOp::new_inplace_a_op(boxed!(RegisterSource::new(other_source_name)), operation, 1));
 }
 
-fn build_subtract_instruction_from_constant_byte() -> Option<Box<dyn Instruction>> {
-    return optional_boxed!(BinaryByteOp::new_inplace_a_op(boxed!(ConstantByteSource::new()), subtract));
+fn build_subtract_instruction_from_memory(operation: BinaryByteOpFn) -> Option<Box<dyn Instruction>> {
+    return optional_boxed!(BinaryByteOp::new_inplace_a_op(boxed!(AddressedByShortSource::new_from_register(DoubleRegisterName::HL)), operation, 2));
+}
+
+fn build_subtract_instruction_from_constant_byte(operation: BinaryByteOpFn) -> Option<Box<dyn Instruction>> {
+    return optional_boxed!(BinaryByteOp::new_inplace_a_op(boxed!(ConstantByteSource::new()), operation, 2));
 }
 
 pub fn load_instruction(instruction_byte: u8) -> Option<Box<dyn Instruction>> {
     return match instruction_byte {
-        0x90 => build_subtract_instruction(RegisterName::B),
-        0x91 => build_subtract_instruction(RegisterName::C),
-        0x92 => build_subtract_instruction(RegisterName::D),
-        0x93 => build_subtract_instruction(RegisterName::E),
-        0x94 => build_subtract_instruction(RegisterName::H),
-        0x95 => build_subtract_instruction(RegisterName::L),
-        0x97 => build_subtract_instruction(RegisterName::A),
-        0xD6 => build_subtract_instruction_from_constant_byte(),
+        // Subtract
+        0x90 => build_subtract_instruction(RegisterName::B, subtract),
+        0x91 => build_subtract_instruction(RegisterName::C, subtract),
+        0x92 => build_subtract_instruction(RegisterName::D, subtract),
+        0x93 => build_subtract_instruction(RegisterName::E, subtract),
+        0x94 => build_subtract_instruction(RegisterName::H, subtract),
+        0x95 => build_subtract_instruction(RegisterName::L, subtract),
+        0x96 => build_subtract_instruction_from_memory(subtract),
+        0x97 => build_subtract_instruction(RegisterName::A, subtract),
+        0xD6 => build_subtract_instruction_from_constant_byte(subtract),
+        // Subtract with Carry
+        0x98 => build_subtract_instruction(RegisterName::B, subtract_with_carry),
+        0x99 => build_subtract_instruction(RegisterName::C, subtract_with_carry),
+        0x9A => build_subtract_instruction(RegisterName::D, subtract_with_carry),
+        0x9B => build_subtract_instruction(RegisterName::E, subtract_with_carry),
+        0x9C => build_subtract_instruction(RegisterName::H, subtract_with_carry),
+        0x9D => build_subtract_instruction(RegisterName::L, subtract_with_carry),
+        0x9E => build_subtract_instruction_from_memory(subtract_with_carry),
+        0x9F => build_subtract_instruction(RegisterName::A, subtract_with_carry),
+        0xDE => build_subtract_instruction_from_constant_byte(subtract_with_carry),
         _ => None,
     };
 }
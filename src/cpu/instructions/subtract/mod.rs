@@ -1,5 +1,6 @@
 pub mod instructions;
 
 mod subtract;
+mod subtract_with_carry;
 
 pub use subtract::subtract;
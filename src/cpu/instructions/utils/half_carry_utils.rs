@@ -3,10 +3,22 @@ pub fn check_half_carry(left_value: u8, right_value: u8) -> bool {
     return get_lower_nibble(left_value) + get_lower_nibble(right_value) > 0xF;
 }
 
+pub fn check_half_carry_with_carry(left_value: u8, right_value: u8, carry: bool) -> bool {
+    return get_lower_nibble(left_value) + get_lower_nibble(right_value) + (carry as u8) > 0xF;
+}
+
+pub fn check_half_carry_short(left_value: u16, right_value: u16) -> bool {
+    return (left_value & 0xFFF) + (right_value & 0xFFF) > 0xFFF;
+}
+
 pub fn check_half_borrow(left_value: u8, right_value: u8) -> bool {
     return get_lower_nibble(right_value) > get_lower_nibble(left_value);
 }
 
+pub fn check_half_borrow_with_carry(left_value: u8, right_value: u8, carry: bool) -> bool {
+    return get_lower_nibble(right_value) + (carry as u8) > get_lower_nibble(left_value);
+}
+
 fn get_lower_nibble(value: u8) -> u8 {
     return value & 0xF;
 }
@@ -35,6 +47,46 @@ mod tests {
         assert_eq!(result, true);
     }
     
+    #[test]
+    fn test_check_half_carry_with_carry_no_lower_nibble_overflow_returns_false() {
+        const LEFT: u8 = 0x0E;
+        const RIGHT: u8 = 0x00;
+        
+        let result = check_half_carry_with_carry(LEFT, RIGHT, true);
+        
+        assert_eq!(result, false);
+    }
+    
+    #[test]
+    fn test_check_half_carry_with_carry_carry_overflows_lower_nibble_returns_true() {
+        const LEFT: u8 = 0x0F;
+        const RIGHT: u8 = 0x00;
+        
+        let result = check_half_carry_with_carry(LEFT, RIGHT, true);
+        
+        assert_eq!(result, true);
+    }
+    
+    #[test]
+    fn test_check_half_carry_short_no_bit_eleven_overflow_returns_false() {
+        const LEFT: u16 = 0x00FF;
+        const RIGHT: u16 = 0x0001;
+        
+        let result = check_half_carry_short(LEFT, RIGHT);
+        
+        assert_eq!(result, false);
+    }
+    
+    #[test]
+    fn test_check_half_carry_short_bit_eleven_overflowed_returns_true() {
+        const LEFT: u16 = 0x0FFF;
+        const RIGHT: u16 = 0x0001;
+        
+        let result = check_half_carry_short(LEFT, RIGHT);
+        
+        assert_eq!(result, true);
+    }
+    
     #[test]
     fn test_check_half_borrow_no_lower_nibble_overflow_returns_false() {
         const LEFT: u8 = 0x20;
@@ -54,4 +106,24 @@ mod tests {
         
         assert_eq!(result, true);
     }
+    
+    #[test]
+    fn test_check_half_borrow_with_carry_no_lower_nibble_overflow_returns_false() {
+        const LEFT: u8 = 0x01;
+        const RIGHT: u8 = 0x00;
+        
+        let result = check_half_borrow_with_carry(LEFT, RIGHT, true);
+        
+        assert_eq!(result, false);
+    }
+    
+    #[test]
+    fn test_check_half_borrow_with_carry_carry_overflows_lower_nibble_returns_true() {
+        const LEFT: u8 = 0x10;
+        const RIGHT: u8 = 0x00;
+        
+        let result = check_half_borrow_with_carry(LEFT, RIGHT, true);
+        
+        assert_eq!(result, true);
+    }
 }
\ No newline at end of file
@@ -7,4 +7,7 @@ pub use adjust_register::dec_double_register;
 pub use adjust_register::inc_double_register;
 pub use byte_address::build_full_address;
 pub use half_carry_utils::check_half_borrow;
+pub use half_carry_utils::check_half_borrow_with_carry;
 pub use half_carry_utils::check_half_carry;
+pub use half_carry_utils::check_half_carry_short;
+pub use half_carry_utils::check_half_carry_with_carry;
@@ -1,17 +1,20 @@
 use super::xor::xor;
 use super::super::instruction::Instruction;
 use super::super::common::binary_byte_op::BinaryByteOp;
-use super::super::sources::constant_byte_source::ConstantByteSource;
-use super::super::sources::register_source::RegisterSource;
-use super::super::super::registers::register_names::RegisterName;
+use super::super::sources::{AddressedByShortSource, ConstantByteSource, RegisterSource};
+use super::super::super::registers::{DoubleRegisterName, RegisterName};
 use crate::{boxed, optional_boxed};
 
 fn build_xor_instruction(other_source_name: RegisterName) -> Option<Box<dyn Instruction>> {
-    return optional_boxed!(BinaryByteOp::new_inplace_a_op(boxed!(RegisterSource::new(other_source_name)), xor));
+    return optional_boxed!(BinaryByteOp::new_inplace_a_op(boxed!(RegisterSource::new(other_source_name)), xor, 1));
+}
+
+fn build_xor_instruction_from_memory() -> Option<Box<dyn Instruction>> {
+    return optional_boxed!(BinaryByteOp::new_inplace_a_op(boxed!(AddressedByShortSource::new_from_register(DoubleRegisterName::HL)), xor, 2));
 }
 
 fn build_xor_instruction_from_constant_byte() -> Option<Box<dyn Instruction>> {
-    return optional_boxed!(BinaryByteOp::new_inplace_a_op(boxed!(ConstantByteSource::new()), xor));
+    return optional_boxed!(BinaryByteOp::new_inplace_a_op(boxed!(ConstantByteSource::new()), xor, 2));
 }
 
 pub fn load_instruction(instruction_byte: u8) -> Option<Box<dyn Instruction>> {
@@ -22,6 +25,7 @@ pub fn load_instruction(instruction_byte: u8) -> Option<Box<dyn Instruction>> {
         0xAB => build_xor_instruction(RegisterName::E),
         0xAC => build_xor_instruction(RegisterName::H),
         0xAD => build_xor_instruction(RegisterName::L),
+        0xAE => build_xor_instruction_from_memory(),
         0xAF => build_xor_instruction(RegisterName::A),
         0xEE => build_xor_instruction_from_constant_byte(),
         _ => None,
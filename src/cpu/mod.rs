@@ -1,11 +1,15 @@
 pub mod cpu;
+pub mod cpu_state;
+pub mod decode_error;
 pub mod instruction_context;
 pub mod registers;
+pub mod trace;
 
 mod instructions;
 mod stack;
 mod program_counter;
 
+pub use decode_error::DecodeError;
 pub use instruction_context::InstructionContext;
 pub use program_counter::ProgramCounter;
 
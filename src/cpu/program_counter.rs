@@ -1,3 +1,4 @@
+use super::decode_error::DecodeError;
 use super::instructions::instruction::Instruction;
 use super::instructions::instructions::load_instruction;
 use crate::emulator::Memory;
@@ -8,12 +9,14 @@ use std::cell::RefCell;
 
 pub struct ProgramCounter {
     _counter: u16,
+    // Bytes read since the current instruction started, operands included
+    _instruction_length: u16,
     pub _memory: Rc<RefCell<Memory>>,
 }
 
 impl ProgramCounter {
     pub fn new(memory: Rc<RefCell<Memory>>) -> ProgramCounter {
-        return ProgramCounter {_counter: 0, _memory: memory};
+        return ProgramCounter {_counter: 0, _instruction_length: 0, _memory: memory};
     }
     
     pub fn get_counter(&self) -> u16 {
@@ -24,19 +27,26 @@ impl ProgramCounter {
         self._counter = new_counter;
     }
     
-    pub fn read_next_instruction(&mut self) -> Box<dyn Instruction> {
+    pub fn get_instruction_length(&self) -> u16 {
+        return self._instruction_length;
+    }
+    
+    pub fn read_next_instruction(&mut self) -> Result<&'static dyn Instruction, DecodeError> {
+        self._instruction_length = 0;
         return load_instruction(self);
     }
     
     pub fn read_next_byte(&mut self) -> u8 {
         let next_byte = self._memory.borrow().read_byte(self._counter);
         self._counter += 1;
+        self._instruction_length += 1;
         return next_byte;
     }
     
     pub fn read_next_short(&mut self) -> u16 {
         let next_short = self._memory.borrow().read_short(self._counter);
         self._counter += 2;
+        self._instruction_length += 2;
         return next_short;
     }
 }
@@ -59,9 +69,23 @@ mod tests {
         program_counter.set_counter(COUNTER);
         program_counter._memory.borrow_mut().write_byte(COUNTER, ADD_INSTRUCTION);
         
-        program_counter.read_next_instruction();
+        let result = program_counter.read_next_instruction();
+        
+        assert_eq!(result.is_ok(), true);
+    }
+    
+    #[test]
+    fn test_read_next_instruction_illegal_opcode_returns_error() {
+        const ILLEGAL_INSTRUCTION: u8 = 0xFC;
+        const COUNTER: u16 = 0xABCD;
+        
+        let mut program_counter = build_program_counter();
+        program_counter.set_counter(COUNTER);
+        program_counter._memory.borrow_mut().write_byte(COUNTER, ILLEGAL_INSTRUCTION);
+        
+        let result = program_counter.read_next_instruction();
         
-        // Not sure if there'e anything I can assert on
+        assert_eq!(result.err(), Some(DecodeError::IllegalOpcode {address: COUNTER, opcode: ILLEGAL_INSTRUCTION}));
     }
     
     #[test]
@@ -73,11 +97,26 @@ mod tests {
         program_counter.set_counter(COUNTER);
         program_counter._memory.borrow_mut().write_byte(COUNTER, ADD_INSTRUCTION);
         
-        program_counter.read_next_instruction();
+        program_counter.read_next_instruction().unwrap();
         
         assert_eq!(program_counter._counter, COUNTER+1);
     }
     
+    #[test]
+    fn test_get_instruction_length_counts_operands() {
+        const LOAD_CONSTANT_SHORT_INSTRUCTION: u8 = 0x01;
+        const COUNTER: u16 = 0xABCD;
+        
+        let mut program_counter = build_program_counter();
+        program_counter.set_counter(COUNTER);
+        program_counter._memory.borrow_mut().write_byte(COUNTER, LOAD_CONSTANT_SHORT_INSTRUCTION);
+        
+        program_counter.read_next_instruction().unwrap();
+        program_counter.read_next_short();
+        
+        assert_eq!(program_counter.get_instruction_length(), 3);
+    }
+    
     #[test]
     fn test_read_next_byte_returns_byte() {
         const ADDRESS: u16 = 0xABCD;
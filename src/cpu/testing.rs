@@ -1,3 +1,4 @@
+use super::cpu_state::CpuState;
 use super::instruction_context::InstructionContext;
 use super::program_counter::ProgramCounter;
 use super::registers::registers::Registers;
@@ -23,5 +24,6 @@ pub fn build_test_instruction_context_with_memory(memory: Rc<RefCell<Memory>>) -
     let program = rc_refcell!(ProgramCounter::new(memory.clone()));
     let registers = rc_refcell!(Registers::new());
     let stack = rc_refcell!(Stack::new(memory.clone()));
-    return InstructionContext::new(memory.clone(), program, registers, stack);
+    let state = rc_refcell!(CpuState::new());
+    return InstructionContext::new(memory.clone(), program, registers, stack, state);
 }
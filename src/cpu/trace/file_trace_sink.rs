@@ -0,0 +1,86 @@
+use super::trace_format::TraceFormat;
+use super::trace_record::TraceRecord;
+use super::trace_sink::TraceSink;
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+pub struct FileTraceSink {
+    _writer: BufWriter<File>,
+    _format: TraceFormat,
+    _failed: bool,
+}
+
+impl FileTraceSink {
+    pub fn new(path: &Path, format: TraceFormat) -> io::Result<FileTraceSink> {
+        let file = File::create(path)?;
+        return Ok(FileTraceSink {_writer: BufWriter::new(file), _format: format, _failed: false});
+    }
+}
+
+impl TraceSink for FileTraceSink {
+    // A full disk stops the trace, not the emulator, so the run can still finish and save
+    fn record(&mut self, record: &TraceRecord) {
+        if self._failed {
+            return;
+        }
+        if let Err(error) = writeln!(self._writer, "{}", self._format.format(record)) {
+            eprintln!("Unable to write trace file, tracing stopped: {}", error);
+            self._failed = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::trace_record::build_test_trace_record;
+    use std::env;
+    use std::fs;
+    
+    #[test]
+    fn test_record_writes_in_requested_format() {
+        let path = env::temp_dir().join("kaoboy_test_record_writes_in_requested_format.log");
+        let record = build_test_trace_record(0x0100);
+        
+        {
+            let mut sink = FileTraceSink::new(&path, TraceFormat::GameboyDoctor).unwrap();
+            sink.record(&record);
+        }
+        
+        let contents = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(contents, format!("{}\n", TraceFormat::GameboyDoctor.format(&record)));
+    }
+    
+    #[test]
+    fn test_record_writes_one_line_per_record() {
+        let path = env::temp_dir().join("kaoboy_test_record_writes_one_line_per_record.log");
+        let first = build_test_trace_record(0x0100);
+        let second = build_test_trace_record(0x0102);
+        
+        {
+            let mut sink = FileTraceSink::new(&path, TraceFormat::Standard).unwrap();
+            sink.record(&first);
+            sink.record(&second);
+        }
+        
+        let contents = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(contents, format!("{}\n{}\n", first, second));
+    }
+    
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_record_full_disk_stops_tracing() {
+        let record = build_test_trace_record(0x0100);
+        let mut sink = FileTraceSink::new(Path::new("/dev/full"), TraceFormat::Standard).unwrap();
+        
+        for _ in 0..1000 {
+            sink.record(&record);
+        }
+        
+        assert_eq!(sink._failed, true);
+    }
+}
@@ -0,0 +1,15 @@
+mod file_trace_sink;
+mod ring_buffer_trace_sink;
+mod stdout_trace_sink;
+mod trace_diff;
+mod trace_format;
+mod trace_record;
+mod trace_sink;
+
+pub use file_trace_sink::FileTraceSink;
+pub use ring_buffer_trace_sink::RingBufferTraceSink;
+pub use stdout_trace_sink::StdoutTraceSink;
+pub use trace_diff::find_first_divergence;
+pub use trace_format::{TraceFormat, GAMEBOY_DOCTOR_LCD_Y};
+pub use trace_record::{TraceRecord, TRACE_BYTES};
+pub use trace_sink::TraceSink;
@@ -0,0 +1,88 @@
+use super::trace_format::TraceFormat;
+use super::trace_record::TraceRecord;
+use super::trace_sink::TraceSink;
+
+use std::collections::VecDeque;
+use std::io::{self, Write};
+
+pub struct RingBufferTraceSink {
+    _capacity: usize,
+    _records: VecDeque<TraceRecord>,
+}
+
+impl RingBufferTraceSink {
+    pub fn new(capacity: usize) -> RingBufferTraceSink {
+        return RingBufferTraceSink {
+            _capacity: capacity,
+            _records: VecDeque::with_capacity(capacity),
+        };
+    }
+    
+    // Oldest record first
+    #[cfg(test)]
+    pub fn records(&self) -> Vec<TraceRecord> {
+        return self._records.iter().cloned().collect();
+    }
+    
+    pub fn dump(&self, writer: &mut dyn Write, format: TraceFormat) -> io::Result<()> {
+        for record in self._records.iter() {
+            writeln!(writer, "{}", format.format(record))?;
+        }
+        return Ok(());
+    }
+}
+
+impl TraceSink for RingBufferTraceSink {
+    fn record(&mut self, record: &TraceRecord) {
+        if self._capacity == 0 {
+            return;
+        }
+        
+        if self._records.len() == self._capacity {
+            self._records.pop_front();
+        }
+        self._records.push_back(*record);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::trace_record::build_test_trace_record;
+    
+    #[test]
+    fn test_record_keeps_last_records_in_order() {
+        const CAPACITY: usize = 2;
+        let mut sink = RingBufferTraceSink::new(CAPACITY);
+        
+        sink.record(&build_test_trace_record(0x0100));
+        sink.record(&build_test_trace_record(0x0102));
+        sink.record(&build_test_trace_record(0x0104));
+        
+        let counters: Vec<u16> = sink.records().iter().map(|record| record.program_counter).collect();
+        assert_eq!(counters, vec![0x0102, 0x0104]);
+    }
+    
+    #[test]
+    fn test_record_zero_capacity_keeps_nothing() {
+        let mut sink = RingBufferTraceSink::new(0);
+        
+        sink.record(&build_test_trace_record(0x0100));
+        
+        assert_eq!(sink.records().len(), 0);
+    }
+    
+    #[test]
+    fn test_dump_writes_one_line_per_record() {
+        let first = build_test_trace_record(0x0100);
+        let second = build_test_trace_record(0x0102);
+        let mut sink = RingBufferTraceSink::new(4);
+        sink.record(&first);
+        sink.record(&second);
+        let mut output: Vec<u8> = Vec::new();
+        
+        sink.dump(&mut output, TraceFormat::Standard).unwrap();
+        
+        assert_eq!(String::from_utf8(output).unwrap(), format!("{}\n{}\n", first, second));
+    }
+}
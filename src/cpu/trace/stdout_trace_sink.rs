@@ -0,0 +1,19 @@
+use super::trace_format::TraceFormat;
+use super::trace_record::TraceRecord;
+use super::trace_sink::TraceSink;
+
+pub struct StdoutTraceSink {
+    _format: TraceFormat,
+}
+
+impl StdoutTraceSink {
+    pub fn new(format: TraceFormat) -> StdoutTraceSink {
+        return StdoutTraceSink {_format: format};
+    }
+}
+
+impl TraceSink for StdoutTraceSink {
+    fn record(&mut self, record: &TraceRecord) {
+        println!("{}", self._format.format(record));
+    }
+}
@@ -0,0 +1,212 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::io::{self, BufRead};
+
+const FLAG_NAMES: [(u8, &str); 4] = [
+    (0x80, "Z"),
+    (0x40, "N"),
+    (0x20, "H"),
+    (0x10, "C"),
+];
+
+pub struct TraceDivergence {
+    pub line_number: usize,
+    pub expected: Option<String>,
+    pub actual: Option<String>,
+    // The matching lines right before the divergence, oldest first
+    pub context: Vec<String>,
+    pub mismatches: Vec<String>,
+}
+
+impl fmt::Display for TraceDivergence {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(formatter, "First divergence at line {}", self.line_number)?;
+        let first_context_line = self.line_number - self.context.len();
+        for (offset, line) in self.context.iter().enumerate() {
+            writeln!(formatter, "  {:>8}  {}", first_context_line + offset, line)?;
+        }
+        writeln!(formatter, "- {:>8}  {}", self.line_number, describe_line(&self.expected))?;
+        writeln!(formatter, "+ {:>8}  {}", self.line_number, describe_line(&self.actual))?;
+        return write!(formatter, "Mismatch: {}", self.mismatches.join(", "));
+    }
+}
+
+pub fn find_first_divergence<E: BufRead, A: BufRead>(expected: E, actual: A, context_lines: usize) -> io::Result<Option<TraceDivergence>> {
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    let mut context = VecDeque::with_capacity(context_lines);
+    let mut line_number = 0;
+    
+    loop {
+        line_number += 1;
+        let expected_line = expected_lines.next().transpose()?;
+        let actual_line = actual_lines.next().transpose()?;
+        
+        if expected_line == actual_line {
+            match expected_line {
+                Some(line) => remember_line(&mut context, line, context_lines),
+                None => return Ok(None),
+            }
+            continue;
+        }
+        
+        let mismatches = find_mismatches(&expected_line, &actual_line);
+        return Ok(Some(TraceDivergence {
+            line_number: line_number,
+            expected: expected_line,
+            actual: actual_line,
+            context: context.into_iter().collect(),
+            mismatches: mismatches,
+        }));
+    }
+}
+
+fn remember_line(context: &mut VecDeque<String>, line: String, context_lines: usize) {
+    if context_lines == 0 {
+        return;
+    }
+    if context.len() == context_lines {
+        context.pop_front();
+    }
+    context.push_back(line);
+}
+
+fn describe_line(line: &Option<String>) -> &str {
+    return match line {
+        Some(line) => line,
+        None => "<end of trace>",
+    };
+}
+
+fn find_mismatches(expected: &Option<String>, actual: &Option<String>) -> Vec<String> {
+    let (expected, actual) = match (expected, actual) {
+        (Some(expected), Some(actual)) => (expected, actual),
+        (None, _) => return vec![String::from("expected trace ended first")],
+        (_, None) => return vec![String::from("actual trace ended first")],
+    };
+    
+    let expected_fields = parse_fields(expected);
+    let actual_fields = parse_fields(actual);
+    let mut mismatches = Vec::new();
+    for (name, expected_value) in expected_fields.iter() {
+        let actual_value = actual_fields.iter().find(|(other_name, _)| other_name == name).map(|(_, value)| value);
+        match actual_value {
+            Some(actual_value) if actual_value == expected_value => {},
+            Some(actual_value) => mismatches.push(describe_mismatch(name, expected_value, actual_value)),
+            None => mismatches.push(format!("{} missing", name)),
+        }
+    }
+    
+    if mismatches.is_empty() {
+        mismatches.push(String::from("line text"));
+    }
+    return mismatches;
+}
+
+// Fields look like NAME:VALUE, words without a name belong to the previous field
+fn parse_fields(line: &str) -> Vec<(String, String)> {
+    let mut fields: Vec<(String, String)> = Vec::new();
+    for word in line.split_whitespace() {
+        match word.find(':') {
+            Some(position) => fields.push((String::from(&word[..position]), String::from(&word[position+1..]))),
+            None => {
+                if let Some((_, value)) = fields.last_mut() {
+                    value.push(' ');
+                    value.push_str(word);
+                }
+            },
+        }
+    }
+    return fields;
+}
+
+fn describe_mismatch(name: &str, expected_value: &str, actual_value: &str) -> String {
+    let description = format!("{} expected {} but was {}", name, expected_value, actual_value);
+    if name != "F" {
+        return description;
+    }
+    
+    let (expected_flags, actual_flags) = match (u8::from_str_radix(expected_value, 16), u8::from_str_radix(actual_value, 16)) {
+        (Ok(expected_flags), Ok(actual_flags)) => (expected_flags, actual_flags),
+        _ => return description,
+    };
+    let changed_flags = expected_flags ^ actual_flags;
+    let flag_names: Vec<&str> = FLAG_NAMES.iter()
+        .filter(|(mask, _)| changed_flags & mask != 0)
+        .map(|(_, flag_name)| *flag_name)
+        .collect();
+    if flag_names.is_empty() {
+        return description;
+    }
+    let suffix = if flag_names.len() == 1 { "flag" } else { "flags" };
+    return format!("{} ({} {})", description, flag_names.join(", "), suffix);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const FIRST_LINE: &str = "A:01 F:B0 B:00 C:13 D:00 E:D8 H:01 L:4D SP:FFFE PC:0100 PCMEM:00,C3,13,02";
+    const SECOND_LINE: &str = "A:01 F:B0 B:00 C:13 D:00 E:D8 H:01 L:4D SP:FFFE PC:0101 PCMEM:C3,13,02,00";
+    const THIRD_LINE: &str = "A:01 F:B0 B:00 C:13 D:00 E:D8 H:01 L:4D SP:FFFE PC:0213 PCMEM:AF,21,00,C0";
+    
+    fn find(expected: &[&str], actual: &[&str], context_lines: usize) -> Option<TraceDivergence> {
+        let expected = expected.join("\n");
+        let actual = actual.join("\n");
+        return find_first_divergence(expected.as_bytes(), actual.as_bytes(), context_lines).unwrap();
+    }
+    
+    #[test]
+    fn test_find_first_divergence_same_traces_returns_none() {
+        let divergence = find(&[FIRST_LINE, SECOND_LINE], &[FIRST_LINE, SECOND_LINE], 2);
+        
+        assert_eq!(divergence.is_none(), true);
+    }
+    
+    #[test]
+    fn test_find_first_divergence_names_register() {
+        let changed_line = SECOND_LINE.replace("B:00", "B:01");
+        
+        let divergence = find(&[FIRST_LINE, SECOND_LINE], &[FIRST_LINE, &changed_line], 2).unwrap();
+        
+        assert_eq!(divergence.line_number, 2);
+        assert_eq!(divergence.mismatches, vec![String::from("B expected 00 but was 01")]);
+    }
+    
+    #[test]
+    fn test_find_first_divergence_names_flags() {
+        let changed_line = SECOND_LINE.replace("F:B0", "F:30");
+        
+        let divergence = find(&[FIRST_LINE, SECOND_LINE], &[FIRST_LINE, &changed_line], 2).unwrap();
+        
+        assert_eq!(divergence.mismatches, vec![String::from("F expected B0 but was 30 (Z flag)")]);
+    }
+    
+    #[test]
+    fn test_find_first_divergence_keeps_last_context_lines() {
+        let changed_line = THIRD_LINE.replace("PC:0213", "PC:0214");
+        
+        let divergence = find(&[FIRST_LINE, SECOND_LINE, THIRD_LINE], &[FIRST_LINE, SECOND_LINE, &changed_line], 1).unwrap();
+        
+        assert_eq!(divergence.line_number, 3);
+        assert_eq!(divergence.context, vec![String::from(SECOND_LINE)]);
+    }
+    
+    #[test]
+    fn test_find_first_divergence_shorter_actual_trace_reports_end() {
+        let divergence = find(&[FIRST_LINE, SECOND_LINE], &[FIRST_LINE], 2).unwrap();
+        
+        assert_eq!(divergence.actual, None);
+        assert_eq!(divergence.mismatches, vec![String::from("actual trace ended first")]);
+    }
+    
+    #[test]
+    fn test_find_first_divergence_standard_format_groups_opcode_bytes() {
+        let expected = "PC:0100 OP:CB 37    A:01 F:B0";
+        let actual = "PC:0100 OP:CB 30    A:01 F:B0";
+        
+        let divergence = find(&[expected], &[actual], 2).unwrap();
+        
+        assert_eq!(divergence.mismatches, vec![String::from("OP expected CB 37 but was CB 30")]);
+    }
+}
@@ -0,0 +1,66 @@
+use super::trace_record::TraceRecord;
+
+// The LY value Gameboy Doctor's reference logs were recorded with, the first line of vblank
+pub const GAMEBOY_DOCTOR_LCD_Y: u8 = 0x90;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TraceFormat {
+    Standard,
+    // Matches the logs from https://github.com/robert/gameboy-doctor, which were recorded with
+    // LY (0xFF44) always reading GAMEBOY_DOCTOR_LCD_Y, so runs in this format map an LcdYStub there
+    GameboyDoctor,
+}
+
+impl TraceFormat {
+    pub fn from_name(name: &str) -> Option<TraceFormat> {
+        return match name {
+            "standard" => Some(TraceFormat::Standard),
+            "doctor" => Some(TraceFormat::GameboyDoctor),
+            _ => None,
+        };
+    }
+    
+    pub fn format(&self, record: &TraceRecord) -> String {
+        return match self {
+            TraceFormat::Standard => record.to_string(),
+            TraceFormat::GameboyDoctor => format_gameboy_doctor(record),
+        };
+    }
+}
+
+fn format_gameboy_doctor(record: &TraceRecord) -> String {
+    let memory: Vec<String> = record.bytes.iter().map(|byte| format!("{:02X}", byte)).collect();
+    return format!(
+        "A:{:02X} F:{:02X} B:{:02X} C:{:02X} D:{:02X} E:{:02X} H:{:02X} L:{:02X} SP:{:04X} PC:{:04X} PCMEM:{}",
+        record.a, record.f, record.b, record.c, record.d, record.e, record.h, record.l,
+        record.stack_pointer, record.program_counter, memory.join(","));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::trace_record::build_test_trace_record;
+    
+    #[test]
+    fn test_from_name_unknown_name_returns_none() {
+        assert_eq!(TraceFormat::from_name("unknown"), None);
+    }
+    
+    #[test]
+    fn test_format_standard_matches_display() {
+        let record = build_test_trace_record(0x0100);
+        
+        assert_eq!(TraceFormat::Standard.format(&record), record.to_string());
+    }
+    
+    #[test]
+    fn test_format_gameboy_doctor_formats_record() {
+        let mut record = build_test_trace_record(0x0100);
+        record.bytes = [0x00, 0xC3, 0x13, 0x02];
+        record.length = 1;
+        
+        assert_eq!(
+            TraceFormat::GameboyDoctor.format(&record),
+            "A:01 F:B0 B:00 C:13 D:00 E:D8 H:01 L:4D SP:FFFE PC:0100 PCMEM:00,C3,13,02");
+    }
+}
@@ -0,0 +1,71 @@
+use std::fmt;
+
+pub const TRACE_BYTES: usize = 4;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TraceRecord {
+    pub program_counter: u16,
+    // The bytes starting at the program counter, only `length` of them belong to the instruction
+    pub bytes: [u8; TRACE_BYTES],
+    pub length: usize,
+    pub a: u8,
+    pub f: u8,
+    pub b: u8,
+    pub c: u8,
+    pub d: u8,
+    pub e: u8,
+    pub h: u8,
+    pub l: u8,
+    pub stack_pointer: u16,
+    pub cycles: u16,
+}
+
+impl TraceRecord {
+    pub fn opcode_bytes(&self) -> &[u8] {
+        return &self.bytes[..self.length.min(TRACE_BYTES)];
+    }
+}
+
+impl fmt::Display for TraceRecord {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let opcode_bytes: Vec<String> = self.opcode_bytes().iter().map(|byte| format!("{:02X}", byte)).collect();
+        return write!(formatter,
+            "PC:{:04X} OP:{:<8} A:{:02X} F:{:02X} B:{:02X} C:{:02X} D:{:02X} E:{:02X} H:{:02X} L:{:02X} SP:{:04X} CYCLES:{}",
+            self.program_counter, opcode_bytes.join(" "),
+            self.a, self.f, self.b, self.c, self.d, self.e, self.h, self.l,
+            self.stack_pointer, self.cycles);
+    }
+}
+
+#[cfg(test)]
+pub fn build_test_trace_record(program_counter: u16) -> TraceRecord {
+    return TraceRecord {
+        program_counter: program_counter,
+        bytes: [0xCB, 0x37, 0x00, 0x00],
+        length: 2,
+        a: 0x01, f: 0xB0, b: 0x00, c: 0x13, d: 0x00, e: 0xD8, h: 0x01, l: 0x4D,
+        stack_pointer: 0xFFFE,
+        cycles: 8,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_opcode_bytes_only_returns_instruction_bytes() {
+        let record = build_test_trace_record(0x0100);
+        
+        assert_eq!(record.opcode_bytes(), &[0xCB, 0x37]);
+    }
+    
+    #[test]
+    fn test_display_formats_record() {
+        let record = build_test_trace_record(0x0100);
+        
+        assert_eq!(
+            record.to_string(),
+            "PC:0100 OP:CB 37    A:01 F:B0 B:00 C:13 D:00 E:D8 H:01 L:4D SP:FFFE CYCLES:8");
+    }
+}
@@ -0,0 +1,5 @@
+use super::trace_record::TraceRecord;
+
+pub trait TraceSink {
+    fn record(&mut self, record: &TraceRecord);
+}
@@ -0,0 +1,69 @@
+use super::Memory;
+use super::super::cpu::cpu::Cpu;
+use crate::rc_refcell;
+
+use std::time::Instant;
+
+const PROGRAM_START: u16 = 0x0100;
+const STACK_START: u16 = 0xDFFE;
+
+// A tight loop that mixes loads, ALU ops, a prefix op, the stack and both jump outcomes
+const PROGRAM: [u8; 20] = [
+    0x21, 0x00, 0xC0, // LD HL,0xC000
+    0x3E, 0x10,       // LD A,0x10
+    0x80,             // ADD A,B
+    0x77,             // LD (HL),A
+    0x23,             // INC HL
+    0xCB, 0x37,       // SWAP A
+    0xA8,             // XOR B
+    0x05,             // DEC B
+    0xC5,             // PUSH BC
+    0xC1,             // POP BC
+    0xFE, 0x00,       // CP 0x00
+    0x20, 0xEE,       // JR NZ,PROGRAM_START
+    0x18, 0xEC,       // JR PROGRAM_START
+];
+
+pub struct BenchmarkResult {
+    pub instructions: u32,
+    pub seconds: f64,
+}
+
+impl BenchmarkResult {
+    pub fn instructions_per_second(&self) -> f64 {
+        return self.instructions as f64 / self.seconds;
+    }
+}
+
+pub fn run_benchmark(instruction_count: u32) -> BenchmarkResult {
+    let mut rom = vec![0; PROGRAM_START as usize];
+    rom.extend_from_slice(&PROGRAM);
+    let mut memory = Memory::new();
+    memory.load_rom(&rom);
+    
+    let mut cpu = Cpu::new(rc_refcell!(memory));
+    cpu._counter.borrow_mut().set_counter(PROGRAM_START);
+    cpu._stack.borrow_mut().set_pointer(STACK_START);
+    
+    let start = Instant::now();
+    for _x in 0..instruction_count {
+        cpu.run_next_instruction().expect("Benchmark program should only contain legal opcodes");
+    }
+    let seconds = start.elapsed().as_secs_f64();
+    
+    return BenchmarkResult {instructions: instruction_count, seconds: seconds};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_run_benchmark_runs_requested_instructions() {
+        const INSTRUCTION_COUNT: u32 = 1000;
+        
+        let result = run_benchmark(INSTRUCTION_COUNT);
+        
+        assert_eq!(result.instructions, INSTRUCTION_COUNT);
+    }
+}
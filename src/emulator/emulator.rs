@@ -1,22 +1,42 @@
 use super::Memory;
+use super::super::cartridge::{build_mapper, Cartridge, CartridgeError, SaveFile};
 use super::super::cpu::cpu::Cpu;
+use super::super::cpu::DecodeError;
+use super::super::timer::Timer;
+use super::super::timer::timer::{DIVIDER_ADDRESS, CONTROL_ADDRESS};
 use crate::rc_refcell;
 
 use std::fs;
+use std::path::Path;
 use std::rc::Rc;
 use std::cell::RefCell;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// About one second of emulated time between battery saves
+const SAVE_INTERVAL_CYCLES: u32 = 4_194_304;
 
 pub struct Emulator {
     pub _cpu: Cpu,
     pub _memory: Rc<RefCell<Memory>>,
+    pub _timer: Rc<RefCell<Timer>>,
+    _save_file: Option<SaveFile>,
+    _stop_requested: Arc<AtomicBool>,
 }
 
 impl Emulator {
     pub fn new() -> Emulator {
         let memory = rc_refcell!(Memory::new());
+        let timer = rc_refcell!(Timer::new(memory.borrow().interrupt_flags()));
+        for address in DIVIDER_ADDRESS..=CONTROL_ADDRESS {
+            memory.borrow_mut().map_io_device(address, timer.clone());
+        }
         return Emulator {
             _cpu: Cpu::new(memory.clone()),
             _memory: memory.clone(),
+            _timer: timer,
+            _save_file: None,
+            _stop_requested: Arc::new(AtomicBool::new(false)),
         };
     }
     
@@ -25,9 +45,70 @@ impl Emulator {
         self._memory.borrow_mut().bootstrap(bootstrap_data);
     }
     
-    pub fn run(&mut self) {
-        loop {
-            self._cpu.run_next_instruction();
+    pub fn load_rom(&mut self, path: &Path) -> Result<(), CartridgeError> {
+        let cartridge = Cartridge::from_file(path)?;
+        for warning in cartridge.warnings() {
+            eprintln!("Warning: {}: {}", path.display(), warning);
+        }
+        let has_battery = cartridge.header().has_battery();
+        let mut mapper = build_mapper(cartridge)?;
+        
+        self._save_file = None;
+        if has_battery {
+            let mut save_file = SaveFile::for_rom(path);
+            if let Some(data) = save_file.load()? {
+                mapper.load_save_data(&data);
+            }
+            self._save_file = Some(save_file);
+        }
+        
+        self._memory.borrow_mut().load_cartridge(mapper);
+        return Ok(());
+    }
+    
+    // Lets a frontend shake the controller while the cartridge's motor runs
+    pub fn is_rumble_motor_on(&self) -> bool {
+        return self._memory.borrow().is_rumble_motor_on();
+    }
+    
+    // Starts the cartridge directly, as if the boot ROM had just finished
+    pub fn skip_bootstrap(&mut self) {
+        self._cpu.reset_to_post_boot_state();
+    }
+    
+    // Setting the flag from anywhere makes run() return after the current instruction
+    pub fn stop_handle(&self) -> Arc<AtomicBool> {
+        return self._stop_requested.clone();
+    }
+    
+    // Runs until stopped or locked up, and saves the battery RAM either way
+    pub fn run(&mut self) -> Result<(), DecodeError> {
+        let result = self.run_until_stopped();
+        self.save_battery();
+        return result;
+    }
+    
+    fn run_until_stopped(&mut self) -> Result<(), DecodeError> {
+        let mut cycles_since_save = 0;
+        while !self._stop_requested.load(Ordering::Relaxed) {
+            let cycles = self._cpu.run_next_instruction()?;
+            self._timer.borrow_mut().tick(cycles);
+            cycles_since_save += cycles as u32;
+            if cycles_since_save >= SAVE_INTERVAL_CYCLES {
+                cycles_since_save = 0;
+                self.save_battery();
+            }
+        }
+        return Ok(());
+    }
+    
+    // A failed save is reported but never stops the game
+    pub fn save_battery(&mut self) {
+        if let Some(save_file) = self._save_file.as_mut() {
+            let data = self._memory.borrow().save_data();
+            if let Err(error) = save_file.write(&data) {
+                eprintln!("Unable to write {}: {}", save_file.path().display(), error);
+            }
         }
     }
 }
@@ -35,6 +116,9 @@ impl Emulator {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use super::super::super::cartridge::testing::{build_temp_rom_path, build_test_rom};
+    use super::super::super::timer::timer::{COUNTER_ADDRESS, TIMER_INTERRUPT_MASK};
+    use std::path::PathBuf;
     
     #[test]
     fn test_bootstrap_sets_bytes() {
@@ -45,4 +129,55 @@ mod tests {
         
         //emulator.bootstrap();
     }
-}
+    
+    fn write_battery_rom(name: &str) -> PathBuf {
+        let path = build_temp_rom_path(name);
+        fs::write(&path, build_test_rom(0x03, 0x00, 0x02)).unwrap();
+        return path;
+    }
+    
+    #[test]
+    fn test_timer_overflow_requests_interrupt_on_bus() {
+        const INTERRUPT_FLAG_ADDRESS: u16 = 0xFF0F;
+        let emulator = Emulator::new();
+        emulator._memory.borrow_mut().write_byte(COUNTER_ADDRESS, 0xFF);
+        emulator._memory.borrow_mut().write_byte(CONTROL_ADDRESS, 0x05);
+        
+        emulator._timer.borrow_mut().tick(20);
+        
+        assert_eq!(emulator._memory.borrow().read_byte(INTERRUPT_FLAG_ADDRESS) & TIMER_INTERRUPT_MASK, TIMER_INTERRUPT_MASK);
+    }
+    
+    #[test]
+    fn test_load_rom_restores_battery_save() {
+        let rom_path = write_battery_rom("restore");
+        fs::write(rom_path.with_extension("sav"), [0x42]).unwrap();
+        let mut emulator = Emulator::new();
+        
+        emulator.load_rom(&rom_path).unwrap();
+        emulator._memory.borrow_mut().write_byte(0x0000, 0x0A);
+        let value = emulator._memory.borrow().read_byte(0xA000);
+        fs::remove_file(rom_path.with_extension("sav")).unwrap();
+        fs::remove_file(&rom_path).unwrap();
+        
+        assert_eq!(value, 0x42);
+    }
+    
+    #[test]
+    fn test_run_stopped_writes_battery_save() {
+        let rom_path = write_battery_rom("shutdown");
+        let mut emulator = Emulator::new();
+        emulator.load_rom(&rom_path).unwrap();
+        emulator._memory.borrow_mut().write_byte(0x0000, 0x0A);
+        emulator._memory.borrow_mut().write_byte(0xA000, 0x99);
+        emulator.stop_handle().store(true, Ordering::Relaxed);
+        
+        let result = emulator.run();
+        let save = fs::read(rom_path.with_extension("sav")).unwrap();
+        fs::remove_file(rom_path.with_extension("sav")).unwrap();
+        fs::remove_file(&rom_path).unwrap();
+        
+        assert_eq!(result.is_ok(), true);
+        assert_eq!(save[0], 0x99);
+    }
+}
\ No newline at end of file
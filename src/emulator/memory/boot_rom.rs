@@ -0,0 +1,82 @@
+use super::io_device::IoDevice;
+
+pub const BOOT_ROM_DISABLE_ADDRESS: u16 = 0xFF50;
+pub const BOOT_ROM_END: u16 = 0x00FF;
+
+const BOOT_ROM_SIZE: usize = BOOT_ROM_END as usize + 1;
+const DISABLED_BIT: u8 = 0x01;
+
+// The 256 byte program that sits on top of the cartridge until it writes to 0xFF50
+pub struct BootRom {
+    _bytes: [u8; BOOT_ROM_SIZE],
+    _mapped: bool,
+}
+
+impl BootRom {
+    pub fn new() -> BootRom {
+        return BootRom {_bytes: [0; BOOT_ROM_SIZE], _mapped: false};
+    }
+    
+    pub fn load(&mut self, data: &[u8]) {
+        for (place, byte) in self._bytes.iter_mut().zip(data.iter()) {
+            *place = *byte;
+        }
+        self._mapped = true;
+    }
+    
+    pub fn is_mapped(&self) -> bool {
+        return self._mapped;
+    }
+    
+    pub fn read_byte(&self, address: u16) -> u8 {
+        return self._bytes[address as usize];
+    }
+}
+
+impl IoDevice for BootRom {
+    fn read_register(&self, _address: u16) -> u8 {
+        return if self._mapped { !DISABLED_BIT } else { 0xFF };
+    }
+    
+    // Once unmapped, nothing maps the overlay back until the next reset
+    fn write_register(&mut self, _address: u16, value: u8) {
+        if value & DISABLED_BIT != 0 {
+            self._mapped = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_load_maps_overlay() {
+        let mut boot_rom = BootRom::new();
+        
+        boot_rom.load(&[0x31, 0xFE, 0xFF]);
+        
+        assert_eq!(boot_rom.is_mapped(), true);
+        assert_eq!(boot_rom.read_byte(0x01), 0xFE);
+    }
+    
+    #[test]
+    fn test_write_register_disabled_bit_unmaps_overlay() {
+        let mut boot_rom = BootRom::new();
+        boot_rom.load(&[0x00]);
+        
+        boot_rom.write_register(BOOT_ROM_DISABLE_ADDRESS, 0x01);
+        
+        assert_eq!(boot_rom.is_mapped(), false);
+    }
+    
+    #[test]
+    fn test_write_register_without_disabled_bit_keeps_overlay() {
+        let mut boot_rom = BootRom::new();
+        boot_rom.load(&[0x00]);
+        
+        boot_rom.write_register(BOOT_ROM_DISABLE_ADDRESS, 0x00);
+        
+        assert_eq!(boot_rom.is_mapped(), true);
+    }
+}
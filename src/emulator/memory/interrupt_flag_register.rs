@@ -0,0 +1,56 @@
+use super::io_device::IoDevice;
+
+pub const INTERRUPT_FLAG_ADDRESS: u16 = 0xFF0F;
+
+const INTERRUPT_MASK: u8 = 0x1F;
+
+// IF, with the three unused upper bits always reading as set
+pub struct InterruptFlagRegister {
+    _requested: u8,
+}
+
+impl InterruptFlagRegister {
+    pub fn new() -> InterruptFlagRegister {
+        return InterruptFlagRegister {_requested: 0};
+    }
+    
+    // How peripherals ask for an interrupt, one bit per source
+    pub fn request(&mut self, mask: u8) {
+        self._requested |= mask & INTERRUPT_MASK;
+    }
+}
+
+impl IoDevice for InterruptFlagRegister {
+    fn read_register(&self, _address: u16) -> u8 {
+        return self._requested | !INTERRUPT_MASK;
+    }
+    
+    fn write_register(&mut self, _address: u16, value: u8) {
+        self._requested = value & INTERRUPT_MASK;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::as_hex;
+    
+    #[test]
+    fn test_read_register_sets_unused_bits() {
+        let mut register = InterruptFlagRegister::new();
+        
+        register.write_register(INTERRUPT_FLAG_ADDRESS, 0x04);
+        
+        assert_eq!(as_hex!(register.read_register(INTERRUPT_FLAG_ADDRESS)), as_hex!(0xE4));
+    }
+    
+    #[test]
+    fn test_request_keeps_other_requests() {
+        let mut register = InterruptFlagRegister::new();
+        register.write_register(INTERRUPT_FLAG_ADDRESS, 0x01);
+        
+        register.request(0x04);
+        
+        assert_eq!(as_hex!(register.read_register(INTERRUPT_FLAG_ADDRESS)), as_hex!(0xE5));
+    }
+}
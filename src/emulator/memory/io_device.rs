@@ -0,0 +1,5 @@
+// A peripheral that owns one or more registers in the 0xFF00-0xFF7F page, addressed by their full address
+pub trait IoDevice {
+    fn read_register(&self, address: u16) -> u8;
+    fn write_register(&mut self, address: u16, value: u8);
+}
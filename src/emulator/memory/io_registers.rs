@@ -0,0 +1,98 @@
+use super::io_device::IoDevice;
+use super::memory_region::MemoryRegion;
+use crate::as_hex;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+pub const IO_REGISTERS_START: u16 = 0xFF00;
+pub const IO_REGISTERS_END: u16 = 0xFF7F;
+
+// What the data bus floats to when no device answers
+const OPEN_BUS_VALUE: u8 = 0xFF;
+
+// Routes every I/O register to the device that owns it
+pub struct IoRegisters {
+    _devices: Vec<Option<Rc<RefCell<dyn IoDevice>>>>,
+}
+
+impl IoRegisters {
+    pub fn new() -> IoRegisters {
+        let size = (IO_REGISTERS_END - IO_REGISTERS_START) as usize + 1;
+        return IoRegisters {_devices: vec![None; size]};
+    }
+    
+    pub fn map_device(&mut self, address: u16, device: Rc<RefCell<dyn IoDevice>>) {
+        if !(IO_REGISTERS_START..=IO_REGISTERS_END).contains(&address) {
+            panic!("{} is not an I/O register address", as_hex!(address));
+        }
+        self._devices[(address - IO_REGISTERS_START) as usize] = Some(device);
+    }
+}
+
+impl MemoryRegion for IoRegisters {
+    fn read_byte(&self, offset: u16) -> u8 {
+        return match &self._devices[offset as usize] {
+            Some(device) => device.borrow().read_register(IO_REGISTERS_START + offset),
+            None => OPEN_BUS_VALUE,
+        };
+    }
+    
+    // Writes to registers nothing owns are lost
+    fn write_byte(&mut self, offset: u16, value: u8) {
+        if let Some(device) = &self._devices[offset as usize] {
+            device.borrow_mut().write_register(IO_REGISTERS_START + offset, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rc_refcell;
+    
+    const DEVICE_ADDRESS: u16 = 0xFF42;
+    
+    struct FakeDevice {
+        last_address: u16,
+        value: u8,
+    }
+    
+    impl IoDevice for FakeDevice {
+        fn read_register(&self, _address: u16) -> u8 {
+            return self.value;
+        }
+        
+        fn write_register(&mut self, address: u16, value: u8) {
+            self.last_address = address;
+            self.value = value;
+        }
+    }
+    
+    #[test]
+    fn test_read_byte_unmapped_register_returns_open_bus_value() {
+        let registers = IoRegisters::new();
+        
+        assert_eq!(as_hex!(registers.read_byte(0x44)), as_hex!(OPEN_BUS_VALUE));
+    }
+    
+    #[test]
+    fn test_write_byte_mapped_register_reaches_device() {
+        let device = rc_refcell!(FakeDevice {last_address: 0, value: 0});
+        let mut registers = IoRegisters::new();
+        registers.map_device(DEVICE_ADDRESS, device.clone());
+        
+        registers.write_byte(DEVICE_ADDRESS - IO_REGISTERS_START, 0x12);
+        
+        assert_eq!(as_hex!(device.borrow().last_address), as_hex!(DEVICE_ADDRESS));
+        assert_eq!(as_hex!(registers.read_byte(DEVICE_ADDRESS - IO_REGISTERS_START)), as_hex!(0x12));
+    }
+    
+    #[test]
+    #[should_panic]
+    fn test_map_device_outside_io_page_panics() {
+        let mut registers = IoRegisters::new();
+        
+        registers.map_device(0xFF80, rc_refcell!(FakeDevice {last_address: 0, value: 0}));
+    }
+}
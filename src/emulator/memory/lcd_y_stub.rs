@@ -0,0 +1,40 @@
+use super::io_device::IoDevice;
+
+pub const LCD_Y_ADDRESS: u16 = 0xFF44;
+
+// Stands in for LY while there is no PPU, so vblank waits fall through instead of spinning
+pub struct LcdYStub {
+    _value: u8,
+}
+
+impl LcdYStub {
+    pub fn new(value: u8) -> LcdYStub {
+        return LcdYStub {_value: value};
+    }
+}
+
+impl IoDevice for LcdYStub {
+    fn read_register(&self, _address: u16) -> u8 {
+        return self._value;
+    }
+    
+    fn write_register(&mut self, _address: u16, _value: u8) {
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::Memory;
+    use crate::{as_hex, rc_refcell};
+    
+    #[test]
+    fn test_read_byte_mapped_stub_returns_value() {
+        let mut memory = Memory::new();
+        memory.map_io_device(LCD_Y_ADDRESS, rc_refcell!(LcdYStub::new(0x90)));
+        
+        memory.write_byte(LCD_Y_ADDRESS, 0x12);
+        
+        assert_eq!(as_hex!(memory.read_byte(LCD_Y_ADDRESS)), as_hex!(0x90));
+    }
+}
@@ -0,0 +1,342 @@
+use super::boot_rom::{BootRom, BOOT_ROM_DISABLE_ADDRESS, BOOT_ROM_END};
+use super::interrupt_flag_register::{InterruptFlagRegister, INTERRUPT_FLAG_ADDRESS};
+use super::io_device::IoDevice;
+use super::io_registers::{IoRegisters, IO_REGISTERS_START, IO_REGISTERS_END};
+use super::memory_region::MemoryRegion;
+use super::ram::Ram;
+use super::super::super::cartridge::{Mapper, RomOnly};
+use crate::{boxed, build_u16, rc_refcell};
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+const ROM_START: u16 = 0x0000;
+const ROM_END: u16 = 0x7FFF;
+const VIDEO_RAM_START: u16 = 0x8000;
+const VIDEO_RAM_END: u16 = 0x9FFF;
+const EXTERNAL_RAM_START: u16 = 0xA000;
+const EXTERNAL_RAM_END: u16 = 0xBFFF;
+const WORK_RAM_START: u16 = 0xC000;
+const WORK_RAM_END: u16 = 0xDFFF;
+const ECHO_RAM_START: u16 = 0xE000;
+const ECHO_RAM_END: u16 = 0xFDFF;
+const OBJECT_ATTRIBUTE_MEMORY_START: u16 = 0xFE00;
+const OBJECT_ATTRIBUTE_MEMORY_END: u16 = 0xFE9F;
+// Without a cartridge the bus behaves like a plain 32 KiB ROM with a bank of RAM
+const NO_CARTRIDGE_RAM_SIZE: usize = 0x2000;
+
+const UNUSABLE_START: u16 = 0xFEA0;
+const UNUSABLE_END: u16 = 0xFEFF;
+const HIGH_RAM_START: u16 = 0xFF80;
+const HIGH_RAM_END: u16 = 0xFFFE;
+const INTERRUPT_ENABLE_ADDRESS: u16 = 0xFFFF;
+
+// What the DMG returns for the prohibited area while OAM is accessible
+const UNUSABLE_VALUE: u8 = 0x00;
+
+// The DMG address bus, which hands every access to the region that owns the address
+pub struct Memory {
+    _boot_rom: Rc<RefCell<BootRom>>,
+    _interrupt_flags: Rc<RefCell<InterruptFlagRegister>>,
+    _cartridge: Box<dyn Mapper>,
+    _video_ram: Ram,
+    _work_ram: Ram,
+    _object_attribute_memory: Ram,
+    _io_registers: IoRegisters,
+    _high_ram: Ram,
+    _interrupt_enable: u8,
+}
+
+impl Memory {
+    pub fn new() -> Memory {
+        let mut io_registers = IoRegisters::new();
+        let interrupt_flags = rc_refcell!(InterruptFlagRegister::new());
+        io_registers.map_device(INTERRUPT_FLAG_ADDRESS, interrupt_flags.clone());
+        let boot_rom = rc_refcell!(BootRom::new());
+        io_registers.map_device(BOOT_ROM_DISABLE_ADDRESS, boot_rom.clone());
+        return Memory {
+            _boot_rom: boot_rom,
+            _interrupt_flags: interrupt_flags,
+            _cartridge: boxed!(RomOnly::new(Vec::new(), NO_CARTRIDGE_RAM_SIZE)),
+            _video_ram: Ram::new(region_size(VIDEO_RAM_START, VIDEO_RAM_END)),
+            _work_ram: Ram::new(region_size(WORK_RAM_START, WORK_RAM_END)),
+            _object_attribute_memory: Ram::new(region_size(OBJECT_ATTRIBUTE_MEMORY_START, OBJECT_ATTRIBUTE_MEMORY_END)),
+            _io_registers: io_registers,
+            _high_ram: Ram::new(region_size(HIGH_RAM_START, HIGH_RAM_END)),
+            _interrupt_enable: 0,
+        };
+    }
+    
+    // Shared with the peripherals that raise interrupts
+    pub fn interrupt_flags(&self) -> Rc<RefCell<InterruptFlagRegister>> {
+        return self._interrupt_flags.clone();
+    }
+    
+    // Hands an I/O register to a peripheral, replacing whatever owned it before
+    pub fn map_io_device(&mut self, address: u16, device: Rc<RefCell<dyn IoDevice>>) {
+        self._io_registers.map_device(address, device);
+    }
+    
+    // Overlays the boot ROM on the start of the cartridge until the program writes to 0xFF50
+    pub fn bootstrap(&mut self, bootstrap_data: Vec<u8>) {
+        self._boot_rom.borrow_mut().load(&bootstrap_data);
+    }
+    
+    pub fn load_rom(&mut self, rom_data: &[u8]) {
+        self.load_cartridge(boxed!(RomOnly::new(rom_data.to_vec(), NO_CARTRIDGE_RAM_SIZE)));
+    }
+    
+    // Puts the cartridge's controller in charge of the ROM and external RAM areas
+    pub fn load_cartridge(&mut self, cartridge: Box<dyn Mapper>) {
+        self._cartridge = cartridge;
+    }
+    
+    // The battery-backed state of the cartridge, empty when it has none
+    pub fn save_data(&self) -> Vec<u8> {
+        return self._cartridge.save_data();
+    }
+    
+    pub fn is_rumble_motor_on(&self) -> bool {
+        return self._cartridge.is_rumble_motor_on();
+    }
+    
+    pub fn read_byte(&self, address: u16) -> u8 {
+        return match address {
+            ROM_START..=ROM_END => self.read_rom_byte(address),
+            VIDEO_RAM_START..=VIDEO_RAM_END => self._video_ram.read_byte(address - VIDEO_RAM_START),
+            EXTERNAL_RAM_START..=EXTERNAL_RAM_END => self._cartridge.read_ram(address - EXTERNAL_RAM_START),
+            WORK_RAM_START..=WORK_RAM_END => self._work_ram.read_byte(address - WORK_RAM_START),
+            ECHO_RAM_START..=ECHO_RAM_END => self._work_ram.read_byte(address - ECHO_RAM_START),
+            OBJECT_ATTRIBUTE_MEMORY_START..=OBJECT_ATTRIBUTE_MEMORY_END =>
+                self._object_attribute_memory.read_byte(address - OBJECT_ATTRIBUTE_MEMORY_START),
+            UNUSABLE_START..=UNUSABLE_END => UNUSABLE_VALUE,
+            IO_REGISTERS_START..=IO_REGISTERS_END => self._io_registers.read_byte(address - IO_REGISTERS_START),
+            HIGH_RAM_START..=HIGH_RAM_END => self._high_ram.read_byte(address - HIGH_RAM_START),
+            INTERRUPT_ENABLE_ADDRESS => self._interrupt_enable,
+        };
+    }
+    
+    fn read_rom_byte(&self, address: u16) -> u8 {
+        if address <= BOOT_ROM_END {
+            let boot_rom = self._boot_rom.borrow();
+            if boot_rom.is_mapped() {
+                return boot_rom.read_byte(address);
+            }
+        }
+        return self._cartridge.read_rom(address);
+    }
+    
+    pub fn read_short(&self, address: u16) -> u16 {
+        let low_byte = self.read_byte(address);
+        let high_byte = self.read_byte(address.wrapping_add(1));
+        return build_u16!(high_byte, low_byte);
+    }
+    
+    pub fn write_byte(&mut self, address: u16, value: u8) {
+        match address {
+            ROM_START..=ROM_END => self._cartridge.write_rom(address, value),
+            VIDEO_RAM_START..=VIDEO_RAM_END => self._video_ram.write_byte(address - VIDEO_RAM_START, value),
+            EXTERNAL_RAM_START..=EXTERNAL_RAM_END => self._cartridge.write_ram(address - EXTERNAL_RAM_START, value),
+            WORK_RAM_START..=WORK_RAM_END => self._work_ram.write_byte(address - WORK_RAM_START, value),
+            ECHO_RAM_START..=ECHO_RAM_END => self._work_ram.write_byte(address - ECHO_RAM_START, value),
+            OBJECT_ATTRIBUTE_MEMORY_START..=OBJECT_ATTRIBUTE_MEMORY_END =>
+                self._object_attribute_memory.write_byte(address - OBJECT_ATTRIBUTE_MEMORY_START, value),
+            UNUSABLE_START..=UNUSABLE_END => {},
+            IO_REGISTERS_START..=IO_REGISTERS_END => self._io_registers.write_byte(address - IO_REGISTERS_START, value),
+            HIGH_RAM_START..=HIGH_RAM_END => self._high_ram.write_byte(address - HIGH_RAM_START, value),
+            INTERRUPT_ENABLE_ADDRESS => self._interrupt_enable = value,
+        }
+    }
+    
+    pub fn write_short(&mut self, address: u16, value: u16) {
+        let bytes = value.to_le_bytes();
+        let low_byte = bytes[0];
+        let high_byte = bytes[1];
+        self.write_byte(address, low_byte);
+        self.write_byte(address.wrapping_add(1), high_byte);
+    }
+}
+
+fn region_size(start: u16, end: u16) -> usize {
+    return (end - start) as usize + 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::super::super::cartridge::{build_mapper, Cartridge};
+    use super::super::super::super::cartridge::testing::build_test_rom;
+    use crate::{as_hex};
+    
+    #[test]
+    fn test_bootstrap_sets_bytes() {
+        const BOOTSTRAP_BYTES: [u8; 10] = [
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x16, 0x17, 0x18, 0x1A, 0x1B,
+        ];
+        let mut memory = Memory::new();
+        let b: Vec<u8> = BOOTSTRAP_BYTES.iter().cloned().collect();
+        
+        memory.bootstrap(b);
+        
+        for (i, expected) in BOOTSTRAP_BYTES.iter().enumerate() {
+            let address: u16 = i as u16;
+            assert_eq!(as_hex!(memory.read_byte(address)), as_hex!(expected));
+        }
+    }
+    
+    #[test]
+    fn test_read_byte_returns_byte() {
+        const ADDRESS: u16 = 0xABCD;
+        const EXPECTED_BYTE: u8 = 0xAB;
+        let mut memory = Memory::new();
+        
+        memory.write_byte(ADDRESS, EXPECTED_BYTE);
+        let result = memory.read_byte(ADDRESS);
+        
+        assert_eq!(as_hex!(result), as_hex!(EXPECTED_BYTE));
+    }
+    
+    #[test]
+    fn test_read_short_returns_short() {
+        const ADDRESS: u16 = 0xABCD;
+        const EXPECTED_SHORT: u16 = 0xFEDC;
+        let mut memory = Memory::new();
+        
+        memory.write_short(ADDRESS, EXPECTED_SHORT);
+        let result = memory.read_short(ADDRESS);
+        
+        assert_eq!(as_hex!(result), as_hex!(EXPECTED_SHORT));
+    }
+    
+    #[test]
+    fn test_write_short_sets_in_proper_order() {
+        const ADDRESS: u16 = 0xABCD;
+        const EXPECTED_SHORT: u16 = 0xFEDC;
+        let bytes = EXPECTED_SHORT.to_le_bytes();
+        let EXPECTED_FIRST_BYTE = bytes[0];
+        let EXPECTED_SECOND_BYTE = bytes[1];
+        let mut memory = Memory::new();
+        
+        memory.write_short(ADDRESS, EXPECTED_SHORT);
+        let firstResult = memory.read_byte(ADDRESS);
+        let secondResult = memory.read_byte(ADDRESS+1);
+        
+        assert_eq!(as_hex!(firstResult), as_hex!(EXPECTED_FIRST_BYTE));
+        assert_eq!(as_hex!(secondResult), as_hex!(EXPECTED_SECOND_BYTE));
+    }
+    
+    #[test]
+    fn test_write_byte_to_rom_is_ignored() {
+        const ADDRESS: u16 = 0x0150;
+        const ROM_BYTE: u8 = 0x3C;
+        let mut memory = Memory::new();
+        memory.load_rom(&vec![ROM_BYTE; 0x0200]);
+        
+        memory.write_byte(ADDRESS, 0x00);
+        
+        assert_eq!(as_hex!(memory.read_byte(ADDRESS)), as_hex!(ROM_BYTE));
+    }
+    
+    #[test]
+    fn test_bootstrap_overlays_rom_until_disabled() {
+        const ADDRESS: u16 = 0x0010;
+        const BOOT_BYTE: u8 = 0x31;
+        const ROM_BYTE: u8 = 0x3C;
+        let mut memory = Memory::new();
+        memory.load_rom(&vec![ROM_BYTE; 0x0200]);
+        memory.bootstrap(vec![BOOT_BYTE; 0x0100]);
+        
+        let before_disable = memory.read_byte(ADDRESS);
+        memory.write_byte(BOOT_ROM_DISABLE_ADDRESS, 0x01);
+        let after_disable = memory.read_byte(ADDRESS);
+        
+        assert_eq!(as_hex!(before_disable), as_hex!(BOOT_BYTE));
+        assert_eq!(as_hex!(after_disable), as_hex!(ROM_BYTE));
+    }
+    
+    #[test]
+    fn test_bootstrap_leaves_cartridge_header_visible() {
+        const HEADER_ADDRESS: u16 = 0x0104;
+        const ROM_BYTE: u8 = 0xCE;
+        let mut memory = Memory::new();
+        memory.load_rom(&vec![ROM_BYTE; 0x0200]);
+        
+        memory.bootstrap(vec![0x00; 0x0100]);
+        
+        assert_eq!(as_hex!(memory.read_byte(HEADER_ADDRESS)), as_hex!(ROM_BYTE));
+    }
+    
+    #[test]
+    fn test_write_byte_to_rom_reaches_cartridge_controller() {
+        let rom = build_test_rom(0x01, 0x02, 0x00);
+        let cartridge = Cartridge::from_bytes(rom).unwrap();
+        let mut memory = Memory::new();
+        memory.load_cartridge(build_mapper(cartridge).unwrap());
+        
+        memory.write_byte(0x2000, 0x03);
+        
+        assert_eq!(as_hex!(memory.read_byte(0x4000)), as_hex!(0x03));
+    }
+    
+    #[test]
+    fn test_echo_ram_mirrors_work_ram() {
+        const WORK_RAM_ADDRESS: u16 = 0xC123;
+        const ECHO_RAM_ADDRESS: u16 = 0xE123;
+        let mut memory = Memory::new();
+        
+        memory.write_byte(WORK_RAM_ADDRESS, 0x12);
+        memory.write_byte(ECHO_RAM_ADDRESS + 1, 0x34);
+        
+        assert_eq!(as_hex!(memory.read_byte(ECHO_RAM_ADDRESS)), as_hex!(0x12));
+        assert_eq!(as_hex!(memory.read_byte(WORK_RAM_ADDRESS + 1)), as_hex!(0x34));
+    }
+    
+    #[test]
+    fn test_unusable_region_ignores_writes() {
+        const ADDRESS: u16 = 0xFEA0;
+        let mut memory = Memory::new();
+        
+        memory.write_byte(ADDRESS, 0xAB);
+        
+        assert_eq!(as_hex!(memory.read_byte(ADDRESS)), as_hex!(UNUSABLE_VALUE));
+    }
+    
+    #[test]
+    fn test_interrupt_enable_register_is_readable_and_writable() {
+        const EXPECTED_BYTE: u8 = 0x1F;
+        let mut memory = Memory::new();
+        
+        memory.write_byte(INTERRUPT_ENABLE_ADDRESS, EXPECTED_BYTE);
+        
+        assert_eq!(as_hex!(memory.read_byte(INTERRUPT_ENABLE_ADDRESS)), as_hex!(EXPECTED_BYTE));
+    }
+    
+    #[test]
+    fn test_unmapped_io_register_reads_open_bus_value() {
+        const LCD_Y_ADDRESS: u16 = 0xFF44;
+        let mut memory = Memory::new();
+        
+        memory.write_byte(LCD_Y_ADDRESS, 0x12);
+        
+        assert_eq!(as_hex!(memory.read_byte(LCD_Y_ADDRESS)), as_hex!(0xFF));
+    }
+    
+    #[test]
+    fn test_interrupt_flag_register_is_mapped() {
+        let mut memory = Memory::new();
+        
+        memory.write_byte(INTERRUPT_FLAG_ADDRESS, 0x01);
+        
+        assert_eq!(as_hex!(memory.read_byte(INTERRUPT_FLAG_ADDRESS)), as_hex!(0xE1));
+    }
+    
+    #[test]
+    fn test_high_ram_does_not_overlap_interrupt_enable_register() {
+        let mut memory = Memory::new();
+        
+        memory.write_short(HIGH_RAM_END, 0xABCD);
+        
+        assert_eq!(as_hex!(memory.read_byte(HIGH_RAM_END)), as_hex!(0xCD));
+        assert_eq!(as_hex!(memory.read_byte(INTERRUPT_ENABLE_ADDRESS)), as_hex!(0xAB));
+    }
+}
\ No newline at end of file
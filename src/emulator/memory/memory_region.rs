@@ -0,0 +1,5 @@
+// A handler for one part of the address space, addressed by the offset from the start of its region
+pub trait MemoryRegion {
+    fn read_byte(&self, offset: u16) -> u8;
+    fn write_byte(&mut self, offset: u16, value: u8);
+}
@@ -0,0 +1,13 @@
+mod boot_rom;
+mod interrupt_flag_register;
+mod io_device;
+mod io_registers;
+mod lcd_y_stub;
+mod memory;
+mod memory_region;
+mod ram;
+
+pub use interrupt_flag_register::InterruptFlagRegister;
+pub use io_device::IoDevice;
+pub use lcd_y_stub::{LcdYStub, LCD_Y_ADDRESS};
+pub use memory::Memory;
@@ -0,0 +1,37 @@
+use super::memory_region::MemoryRegion;
+
+pub struct Ram {
+    _bytes: Vec<u8>,
+}
+
+impl Ram {
+    pub fn new(size: usize) -> Ram {
+        return Ram {_bytes: vec![0; size]};
+    }
+}
+
+impl MemoryRegion for Ram {
+    fn read_byte(&self, offset: u16) -> u8 {
+        return self._bytes[offset as usize];
+    }
+    
+    fn write_byte(&mut self, offset: u16, value: u8) {
+        self._bytes[offset as usize] = value;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_read_byte_returns_written_byte() {
+        const OFFSET: u16 = 0x12;
+        const EXPECTED_BYTE: u8 = 0xAB;
+        let mut ram = Ram::new(0x20);
+        
+        ram.write_byte(OFFSET, EXPECTED_BYTE);
+        
+        assert_eq!(ram.read_byte(OFFSET), EXPECTED_BYTE);
+    }
+}
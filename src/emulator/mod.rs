@@ -1,5 +1,7 @@
 mod memory;
+pub mod benchmark;
 pub mod emulator;
 
+pub use benchmark::run_benchmark;
 pub use emulator::Emulator;
-pub use memory::Memory;
+pub use memory::{InterruptFlagRegister, IoDevice, LcdYStub, Memory, LCD_Y_ADDRESS};
@@ -1,15 +1,114 @@
 mod bit_helpers;
+mod cartridge;
 mod cpu;
 mod emulator;
+mod shutdown_signal;
+mod timer;
 mod utils;
 
-use emulator::Emulator;
+use emulator::{Emulator, LcdYStub, run_benchmark, LCD_Y_ADDRESS};
 use cpu::registers::{DoubleRegisterName, RegisterName};
+use cpu::trace::{find_first_divergence, FileTraceSink, RingBufferTraceSink, StdoutTraceSink, TraceFormat, GAMEBOY_DOCTOR_LCD_Y};
+use shutdown_signal::stop_on_shutdown_signal;
+
+use std::env;
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::path::Path;
+use std::process;
+use std::sync::atomic::Ordering;
+use std::thread;
+use std::time::Duration;
+
+const DEFAULT_BENCHMARK_INSTRUCTIONS: u32 = 10_000_000;
+const DEFAULT_DIFF_CONTEXT_LINES: usize = 5;
 
 fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() > 1 && args[1] == "bench" {
+        let instruction_count = match args.get(2) {
+            Some(count) => count.parse().expect("Instruction count must be a number"),
+            None => DEFAULT_BENCHMARK_INSTRUCTIONS,
+        };
+        let result = run_benchmark(instruction_count);
+        eprintln!("Ran {} instructions in {:.3}s ({:.0} instructions/second)",
+            result.instructions, result.seconds, result.instructions_per_second());
+        return;
+    }
+    if args.len() > 1 && args[1] == "diff" {
+        if args.len() < 4 {
+            eprintln!("Usage: {} diff <expected trace> <actual trace> [--context N]", args[0]);
+            process::exit(2);
+        }
+        let context_lines = match get_option(&args, "--context") {
+            Some(count) => count.parse().expect("Context line count must be a number"),
+            None => DEFAULT_DIFF_CONTEXT_LINES,
+        };
+        let expected = BufReader::new(File::open(&args[2]).expect("Unable to open expected trace"));
+        let actual = BufReader::new(File::open(&args[3]).expect("Unable to open actual trace"));
+        match find_first_divergence(expected, actual, context_lines).expect("Unable to read trace files") {
+            Some(divergence) => {
+                println!("{}", divergence);
+                process::exit(1);
+            },
+            None => println!("Traces match"),
+        }
+        return;
+    }
+    
     let mut emulator = Emulator::new();
-    emulator.bootstrap();
-    emulator.run();
+    let trace_format = match get_option(&args, "--trace-format") {
+        Some(name) => TraceFormat::from_name(name).expect("Trace format must be standard or doctor"),
+        None => TraceFormat::Standard,
+    };
+    if trace_format == TraceFormat::GameboyDoctor {
+        emulator._memory.borrow_mut().map_io_device(LCD_Y_ADDRESS, rc_refcell!(LcdYStub::new(GAMEBOY_DOCTOR_LCD_Y)));
+    }
+    let mut last_instructions = None;
+    if has_flag(&args, "--trace") {
+        emulator._cpu.set_trace_sink(rc_refcell!(StdoutTraceSink::new(trace_format)));
+    } else if let Some(path) = get_option(&args, "--trace-file") {
+        let sink = FileTraceSink::new(Path::new(path), trace_format).expect("Unable to create trace file");
+        emulator._cpu.set_trace_sink(rc_refcell!(sink));
+    } else if let Some(count) = get_option(&args, "--trace-last") {
+        let count = count.parse().expect("Trace count must be a number");
+        let sink = rc_refcell!(RingBufferTraceSink::new(count));
+        emulator._cpu.set_trace_sink(sink.clone());
+        last_instructions = Some(sink);
+    }
+    
+    let rom_path = get_option(&args, "--rom");
+    if let Some(path) = rom_path {
+        if let Err(error) = emulator.load_rom(Path::new(path)) {
+            eprintln!("Unable to load {}: {}", path, error);
+            process::exit(1);
+        }
+    }
+    // A cartridge starts straight at 0x0100 unless the boot ROM is asked to hand off to it
+    if rom_path.is_none() || has_flag(&args, "--boot") {
+        emulator.bootstrap();
+    } else {
+        emulator.skip_bootstrap();
+    }
+    // Ctrl-C stops the emulator the same way --seconds does, so the battery RAM is still saved
+    if let Err(error) = stop_on_shutdown_signal(emulator.stop_handle()) {
+        eprintln!("Unable to handle Ctrl-C, quitting that way will not save: {}", error);
+    }
+    if let Some(seconds) = get_option(&args, "--seconds") {
+        let seconds = seconds.parse().expect("Seconds must be a number");
+        let stop = emulator.stop_handle();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_secs(seconds));
+            stop.store(true, Ordering::Relaxed);
+        });
+    }
+    if let Err(error) = emulator.run() {
+        eprintln!("CPU locked up: {}", error);
+        if let Some(sink) = last_instructions {
+            eprintln!("Last instructions:");
+            sink.borrow().dump(&mut io::stderr(), trace_format).expect("Unable to dump trace");
+        }
+    }
     //for _x in 0..787 {
     //    emulator._cpu.run_next_instruction();
     //}
@@ -18,6 +117,7 @@ fn main() {
     println!("HL: {}", as_hex!(emulator._cpu._registers.borrow_mut().hl));
     println!("0xFF11: {}", as_hex!(emulator._memory.borrow_mut().read_byte(0xFF11)));
     println!("A: {}", as_hex!(emulator._cpu._registers.borrow().a));
+    println!("Rumble motor on: {}", emulator.is_rumble_motor_on());
     
     
     /*let program: [u8; 81] = [
@@ -263,3 +363,12 @@ fn main() {
     emulator._cpu.run_next_instruction();
     println!("Program Counter After JR Z: {}", emulator._cpu._counter.borrow().get_counter());*/
 }
+
+fn has_flag(args: &[String], name: &str) -> bool {
+    return args.iter().any(|arg| arg == name);
+}
+
+fn get_option<'a>(args: &'a [String], name: &str) -> Option<&'a str> {
+    let position = args.iter().position(|arg| arg == name)?;
+    return args.get(position + 1).map(|value| value.as_str());
+}
@@ -0,0 +1,47 @@
+use std::process;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// What shells report for a process ended by Ctrl-C
+const INTERRUPTED_EXIT_CODE: i32 = 130;
+
+// Turns Ctrl-C and SIGTERM into a stop request so Emulator::run can return and save the battery RAM.
+// A second signal quits straight away in case the emulator never gets back to check the flag.
+// Only one handler can be installed per process, later calls return ctrlc::Error::MultipleHandlers.
+pub fn stop_on_shutdown_signal(stop_requested: Arc<AtomicBool>) -> Result<(), ctrlc::Error> {
+    return ctrlc::set_handler(move || {
+        if request_stop(&stop_requested) {
+            process::exit(INTERRUPTED_EXIT_CODE);
+        }
+    });
+}
+
+// Returns whether a stop had already been asked for
+fn request_stop(stop_requested: &AtomicBool) -> bool {
+    return stop_requested.swap(true, Ordering::Relaxed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_request_stop_sets_flag() {
+        let stop_requested = AtomicBool::new(false);
+        
+        let already_requested = request_stop(&stop_requested);
+        
+        assert_eq!(already_requested, false);
+        assert_eq!(stop_requested.load(Ordering::Relaxed), true);
+    }
+    
+    #[test]
+    fn test_request_stop_twice_reports_earlier_request() {
+        let stop_requested = AtomicBool::new(false);
+        request_stop(&stop_requested);
+        
+        let already_requested = request_stop(&stop_requested);
+        
+        assert_eq!(already_requested, true);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod timer;
+
+pub use timer::Timer;
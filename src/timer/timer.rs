@@ -0,0 +1,218 @@
+use super::super::emulator::{InterruptFlagRegister, IoDevice};
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+pub const DIVIDER_ADDRESS: u16 = 0xFF04;
+pub const COUNTER_ADDRESS: u16 = 0xFF05;
+pub const MODULO_ADDRESS: u16 = 0xFF06;
+pub const CONTROL_ADDRESS: u16 = 0xFF07;
+
+pub const TIMER_INTERRUPT_MASK: u8 = 0x04;
+
+const T_CYCLES_PER_M_CYCLE: u16 = 4;
+const ENABLE_BIT: u8 = 0x04;
+const CLOCK_SELECT_MASK: u8 = 0x03;
+const CONTROL_UNUSED_BITS: u8 = 0xF8;
+// The divider bit whose falling edge ticks TIMA, for each clock select value
+const CLOCK_SELECT_BITS: [u16; 4] = [1 << 9, 1 << 3, 1 << 5, 1 << 7];
+
+// DIV, TIMA, TMA and TAC, all driven by one 16-bit divider counting T-cycles
+pub struct Timer {
+    _interrupt_flags: Rc<RefCell<InterruptFlagRegister>>,
+    _divider: u16,
+    _counter: u8,
+    _modulo: u8,
+    _control: u8,
+    // TIMA reads 0x00 for one M-cycle after it overflows, then picks up TMA
+    _reload_pending: bool,
+}
+
+impl Timer {
+    pub fn new(interrupt_flags: Rc<RefCell<InterruptFlagRegister>>) -> Timer {
+        return Timer {
+            _interrupt_flags: interrupt_flags,
+            _divider: 0,
+            _counter: 0,
+            _modulo: 0,
+            _control: 0,
+            _reload_pending: false,
+        };
+    }
+    
+    pub fn tick(&mut self, t_cycles: u16) {
+        for _x in 0..(t_cycles / T_CYCLES_PER_M_CYCLE) {
+            self.step();
+        }
+    }
+    
+    fn step(&mut self) {
+        if self._reload_pending {
+            self._reload_pending = false;
+            self._counter = self._modulo;
+            self._interrupt_flags.borrow_mut().request(TIMER_INTERRUPT_MASK);
+        }
+        
+        let was_high = self.is_input_high();
+        self._divider = self._divider.wrapping_add(T_CYCLES_PER_M_CYCLE);
+        self.check_falling_edge(was_high);
+    }
+    
+    // TIMA sees the selected divider bit ANDed with the enable bit
+    fn is_input_high(&self) -> bool {
+        let selected_bit = CLOCK_SELECT_BITS[(self._control & CLOCK_SELECT_MASK) as usize];
+        return self._control & ENABLE_BIT != 0 && self._divider & selected_bit != 0;
+    }
+    
+    fn check_falling_edge(&mut self, was_high: bool) {
+        if was_high && !self.is_input_high() {
+            self.increment_counter();
+        }
+    }
+    
+    fn increment_counter(&mut self) {
+        let (counter, overflowed) = self._counter.overflowing_add(1);
+        self._counter = counter;
+        if overflowed {
+            self._reload_pending = true;
+        }
+    }
+}
+
+impl IoDevice for Timer {
+    fn read_register(&self, address: u16) -> u8 {
+        return match address {
+            DIVIDER_ADDRESS => (self._divider >> 8) as u8,
+            COUNTER_ADDRESS => self._counter,
+            MODULO_ADDRESS => self._modulo,
+            _ => self._control | CONTROL_UNUSED_BITS,
+        };
+    }
+    
+    // Changing DIV or TAC can drop the timer input too, which ticks TIMA like any other falling edge
+    fn write_register(&mut self, address: u16, value: u8) {
+        let was_high = self.is_input_high();
+        match address {
+            DIVIDER_ADDRESS => self._divider = 0,
+            COUNTER_ADDRESS => {
+                // Writing during the delay cancels the reload and the interrupt
+                self._counter = value;
+                self._reload_pending = false;
+            },
+            MODULO_ADDRESS => self._modulo = value,
+            _ => self._control = value & !CONTROL_UNUSED_BITS,
+        }
+        self.check_falling_edge(was_high);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rc_refcell;
+    
+    const INTERRUPT_FLAG_ADDRESS: u16 = 0xFF0F;
+    // Enabled, ticking every 16 T-cycles
+    const FAST_CONTROL: u8 = 0x05;
+    
+    fn build_timer() -> (Timer, Rc<RefCell<InterruptFlagRegister>>) {
+        let interrupt_flags = rc_refcell!(InterruptFlagRegister::new());
+        return (Timer::new(interrupt_flags.clone()), interrupt_flags);
+    }
+    
+    fn is_timer_interrupt_requested(interrupt_flags: &Rc<RefCell<InterruptFlagRegister>>) -> bool {
+        return interrupt_flags.borrow().read_register(INTERRUPT_FLAG_ADDRESS) & TIMER_INTERRUPT_MASK != 0;
+    }
+    
+    #[test]
+    fn test_tick_advances_divider_every_256_cycles() {
+        let (mut timer, _interrupt_flags) = build_timer();
+        
+        timer.tick(256 * 3 + 252);
+        
+        assert_eq!(timer.read_register(DIVIDER_ADDRESS), 3);
+    }
+    
+    #[test]
+    fn test_write_divider_resets_divider() {
+        let (mut timer, _interrupt_flags) = build_timer();
+        timer.tick(1024);
+        
+        timer.write_register(DIVIDER_ADDRESS, 0x12);
+        
+        assert_eq!(timer.read_register(DIVIDER_ADDRESS), 0);
+    }
+    
+    #[test]
+    fn test_tick_increments_counter_at_selected_rate() {
+        let (mut timer, _interrupt_flags) = build_timer();
+        timer.write_register(CONTROL_ADDRESS, FAST_CONTROL);
+        
+        timer.tick(16 * 5);
+        
+        assert_eq!(timer.read_register(COUNTER_ADDRESS), 5);
+    }
+    
+    #[test]
+    fn test_tick_disabled_timer_keeps_counter() {
+        let (mut timer, _interrupt_flags) = build_timer();
+        timer.write_register(CONTROL_ADDRESS, FAST_CONTROL & !ENABLE_BIT);
+        
+        timer.tick(16 * 5);
+        
+        assert_eq!(timer.read_register(COUNTER_ADDRESS), 0);
+    }
+    
+    #[test]
+    fn test_write_divider_with_selected_bit_high_increments_counter() {
+        let (mut timer, _interrupt_flags) = build_timer();
+        timer.write_register(CONTROL_ADDRESS, FAST_CONTROL);
+        timer.tick(8);
+        
+        timer.write_register(DIVIDER_ADDRESS, 0x00);
+        
+        assert_eq!(timer.read_register(COUNTER_ADDRESS), 1);
+    }
+    
+    #[test]
+    fn test_overflow_reloads_modulo_one_m_cycle_later() {
+        let (mut timer, interrupt_flags) = build_timer();
+        timer.write_register(MODULO_ADDRESS, 0xAB);
+        timer.write_register(COUNTER_ADDRESS, 0xFF);
+        timer.write_register(CONTROL_ADDRESS, FAST_CONTROL);
+        
+        timer.tick(16);
+        let counter_during_delay = timer.read_register(COUNTER_ADDRESS);
+        let requested_during_delay = is_timer_interrupt_requested(&interrupt_flags);
+        timer.tick(4);
+        
+        assert_eq!(counter_during_delay, 0x00);
+        assert_eq!(requested_during_delay, false);
+        assert_eq!(timer.read_register(COUNTER_ADDRESS), 0xAB);
+        assert_eq!(is_timer_interrupt_requested(&interrupt_flags), true);
+    }
+    
+    #[test]
+    fn test_write_counter_during_delay_cancels_reload() {
+        let (mut timer, interrupt_flags) = build_timer();
+        timer.write_register(MODULO_ADDRESS, 0xAB);
+        timer.write_register(COUNTER_ADDRESS, 0xFF);
+        timer.write_register(CONTROL_ADDRESS, FAST_CONTROL);
+        timer.tick(16);
+        
+        timer.write_register(COUNTER_ADDRESS, 0x10);
+        timer.tick(4);
+        
+        assert_eq!(timer.read_register(COUNTER_ADDRESS), 0x10);
+        assert_eq!(is_timer_interrupt_requested(&interrupt_flags), false);
+    }
+    
+    #[test]
+    fn test_read_control_sets_unused_bits() {
+        let (mut timer, _interrupt_flags) = build_timer();
+        
+        timer.write_register(CONTROL_ADDRESS, FAST_CONTROL);
+        
+        assert_eq!(timer.read_register(CONTROL_ADDRESS), 0xFD);
+    }
+}